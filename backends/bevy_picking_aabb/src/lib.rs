@@ -0,0 +1,242 @@
+//! A picking backend for `bevy_mod_picking` that intersects pointer rays with the [`Aabb`] of
+//! entities.
+//!
+//! # Usage
+//!
+//! Pointers will automatically pick any entity with an [`Aabb`] and a [`GlobalTransform`]. `bevy`
+//! computes an [`Aabb`] for every mesh and sprite, so this works out of the box. Because an
+//! [`Aabb`] only approximates the shape of an entity, this backend is best suited to boxy objects,
+//! or to coarse picking in scenes too large to raycast against meshes.
+//!
+//! To ignore an entity, you can add [`Pickable::IGNORE`] to it, and it will be ignored during
+//! picking.
+//!
+//! For fine-grained control, see the [`AabbBackendSettings::require_markers`] setting.
+//!
+//! ## Visible Entities Only
+//!
+//! In large open worlds, most entities are usually off-screen. Setting
+//! [`AabbBackendSettings::visible_only`] skips every entity that is not visible according to its
+//! [`ViewVisibility`], reusing the frustum culling `bevy` has already done instead of testing
+//! off-screen boxes. Note that [`ViewVisibility`] is `true` if the entity is visible to *any* view,
+//! so with multiple cameras, entities visible to one camera are still tested against the others.
+
+#![allow(clippy::type_complexity)]
+#![allow(clippy::too_many_arguments)]
+#![deny(missing_docs)]
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::{Ray3d, Vec3, Vec3A};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{prelude::*, primitives::Aabb, view::RenderLayers};
+use bevy_transform::prelude::*;
+
+use bevy_picking_core::backend::prelude::*;
+
+/// Commonly used imports.
+pub mod prelude {
+    pub use crate::{AabbBackend, AabbBackendSettings, AabbPickable};
+}
+
+/// Adds the [`Aabb`] picking backend to your app.
+#[derive(Clone)]
+pub struct AabbBackend;
+impl Plugin for AabbBackend {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AabbBackendSettings>()
+            .add_systems(PreUpdate, update_hits.in_set(BackendSet::Primary))
+            .register_type::<AabbBackendSettings>()
+            .register_type::<AabbPickable>();
+    }
+}
+
+/// Runtime settings for the [`AabbBackend`].
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource, Default)]
+pub struct AabbBackendSettings {
+    /// When set to `true` picking will only happen between cameras and entities marked with
+    /// [`AabbPickable`]. Off by default. This setting is provided to give you fine-grained
+    /// control over which cameras and entities should be used by the aabb backend at runtime.
+    pub require_markers: bool,
+    /// When set to `true`, only entities that are visible according to their [`ViewVisibility`]
+    /// are picked, so off-screen entities are never tested. Off by default.
+    pub visible_only: bool,
+}
+
+/// Optional. Marks cameras and target entities that should be used in the aabb picking backend.
+/// Only needed if [`AabbBackendSettings::require_markers`] is set to true.
+#[derive(Debug, Clone, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct AabbPickable;
+
+/// Intersects the rays in the [`RayMap`] with the [`Aabb`] of each entity, using
+/// [`AabbBackendSettings`], then outputs [`PointerHits`].
+pub fn update_hits(
+    picking_cameras: Query<(&Camera, Option<&AabbPickable>, Option<&RenderLayers>)>,
+    ray_map: Res<RayMap>,
+    targets: Query<(
+        Entity,
+        &Aabb,
+        &GlobalTransform,
+        &ViewVisibility,
+        Option<&Pickable>,
+        Option<&AabbPickable>,
+        Option<&RenderLayers>,
+    )>,
+    backend_settings: Res<AabbBackendSettings>,
+    mut output_events: EventWriter<PointerHits>,
+) {
+    for (&ray_id, &ray) in ray_map.map().iter() {
+        let Ok((camera, cam_pickable, cam_layers)) = picking_cameras.get(ray_id.camera) else {
+            continue;
+        };
+        if backend_settings.require_markers && cam_pickable.is_none() || !camera_picks(camera) {
+            continue;
+        }
+
+        let cam_layers = cam_layers.cloned().unwrap_or_default();
+
+        let picks: Vec<(Entity, HitData)> = targets
+            .iter()
+            .filter(|(_, _, _, visibility, ..)| !backend_settings.visible_only || visibility.get())
+            .filter(|(.., pickable, marker, layers)| {
+                let marker_requirement = !backend_settings.require_markers || marker.is_some();
+
+                // Other entities missing render layers are on the default layer 0
+                let entity_layers = layers.cloned().unwrap_or_default();
+                let render_layers_match = cam_layers.intersects(&entity_layers);
+
+                let is_pickable = *pickable != Some(&Pickable::IGNORE);
+
+                marker_requirement && render_layers_match && is_pickable
+            })
+            .filter_map(|(entity, aabb, transform, ..)| {
+                let (distance, normal) = ray_aabb_intersection(ray, aabb, transform)?;
+                let position = ray.get_point(distance);
+                let hit_data = HitData::new(ray_id.camera, distance, Some(position), Some(normal));
+                Some((entity, hit_data))
+            })
+            .collect();
+
+        if !picks.is_empty() {
+            output_events.send(PointerHits::new(ray_id.pointer, picks, camera.order as f32));
+        }
+    }
+}
+
+/// Intersects the `ray` with the `aabb` of an entity with the supplied `transform`. Returns the
+/// distance along the ray to the intersection, and the world space normal of the face that was
+/// hit.
+fn ray_aabb_intersection(
+    ray: Ray3d,
+    aabb: &Aabb,
+    transform: &GlobalTransform,
+) -> Option<(f32, Vec3)> {
+    // Test in the entity's local space, where the box is axis aligned. The ray direction is not
+    // normalized here, so distances along the local ray are the same as along the world ray.
+    let world_to_local = transform.affine().inverse();
+    let origin = Vec3A::from(world_to_local.transform_point3(ray.origin));
+    let direction = Vec3A::from(world_to_local.transform_vector3(*ray.direction));
+
+    // Slab test: the distances to the near and far planes of the box on each axis.
+    let t1 = (aabb.min() - origin) / direction;
+    let t2 = (aabb.max() - origin) / direction;
+    let t_near = t1.min(t2);
+    let t_far = t1.max(t2);
+    let enter = t_near.max_element();
+    let exit = t_far.min_element();
+    if enter > exit || exit < 0.0 {
+        return None;
+    }
+
+    // The face that was hit is on the axis the ray entered the box last.
+    let axis = if t_near.x >= t_near.y && t_near.x >= t_near.z {
+        0
+    } else if t_near.y >= t_near.z {
+        1
+    } else {
+        2
+    };
+    let mut local_normal = Vec3A::ZERO;
+    local_normal[axis] = -direction[axis].signum();
+    // Normals are transformed by the inverse transpose of the local to world transform.
+    let normal = (world_to_local.matrix3.transpose() * local_normal).normalize_or_zero();
+
+    // Rays starting inside the box hit it where they start.
+    Some((enter.max(0.0), normal.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetEvent, Assets};
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_math::Vec2;
+    use bevy_picking_core::{pointer::Location, CorePlugin, PointerCoreBundle};
+    use bevy_render::{
+        camera::{camera_system, ManualTextureViews, RenderTarget},
+        texture::Image,
+    };
+    use bevy_window::{
+        PrimaryWindow, Window, WindowCreated, WindowRef, WindowResized, WindowScaleFactorChanged,
+    };
+
+    use super::*;
+
+    /// Returns the entities hit by a pointer in the center of a 100x100 window, through a camera
+    /// looking at a box.
+    fn hits(is_active: bool) -> Vec<Entity> {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, AabbBackend))
+            .init_resource::<Assets<Image>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_event::<AssetEvent<Image>>();
+        let mut window = Window::default();
+        window.resolution.set(100.0, 100.0);
+        let window = app.world_mut().spawn((window, PrimaryWindow)).id();
+        let location = Location {
+            target: RenderTarget::Window(WindowRef::Primary)
+                .normalize(Some(window))
+                .unwrap(),
+            position: Vec2::splat(50.0),
+        };
+        app.world_mut()
+            .spawn(PointerCoreBundle::new(PointerId::Mouse).with_location(location));
+        app.world_mut().spawn((
+            Camera {
+                is_active,
+                ..Default::default()
+            },
+            OrthographicProjection::default(),
+            GlobalTransform::from_translation(Vec3::Z * 100.0),
+        ));
+        let target = app
+            .world_mut()
+            .spawn((
+                Aabb::from_min_max(Vec3::splat(-5.0), Vec3::splat(5.0)),
+                GlobalTransform::IDENTITY,
+                ViewVisibility::default(),
+            ))
+            .id();
+        app.world_mut()
+            .run_system_once(camera_system::<OrthographicProjection>);
+        app.update();
+
+        let hits = app.world().resource::<Events<PointerHits>>();
+        let hits: Vec<_> = hits
+            .iter_current_update_events()
+            .flat_map(|hits| hits.picks.iter().map(|(entity, _)| *entity))
+            .collect();
+        assert!(hits.iter().all(|entity| *entity == target));
+        hits
+    }
+
+    #[test]
+    fn inactive_camera_does_not_pick() {
+        assert_eq!(hits(true).len(), 1);
+        assert!(hits(false).is_empty());
+    }
+}
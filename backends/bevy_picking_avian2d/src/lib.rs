@@ -0,0 +1,137 @@
+//! A picking backend for `bevy_mod_picking` that uses `Avian` 2d colliders.
+//!
+//! # Usage
+//!
+//! Pointers will automatically pick the colliders under them in the `avian2d` scene.
+//!
+//! To ignore an entity, you can add [`Pickable::IGNORE`] to it, and it will be ignored during
+//! picking.
+//!
+//! For fine-grained control, see the [`Avian2dBackendSettings::require_markers`] setting.
+//!
+//! ## Implementation Notes
+//!
+//! - 2d colliders have no thickness along the Z axis, so instead of casting a ray into the scene,
+//!   this backend finds every collider that contains the pointer's position in the 2d world. This
+//!   assumes the camera looks down the Z axis, as 2d cameras do.
+//! - The depth of each hit is the distance from the camera's near plane to the collider's
+//!   [`GlobalTransform`] along the view direction, so colliders with a higher Z are picked first.
+//! - Unlike the 3d `avian` backend, every collider under the pointer is reported, so the
+//!   [`Pickable`] component can be used to let pointers hover multiple overlapping colliders.
+
+#![allow(clippy::type_complexity)]
+#![allow(clippy::too_many_arguments)]
+#![deny(missing_docs)]
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::Vec3;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{prelude::*, view::RenderLayers};
+use bevy_transform::prelude::*;
+
+use avian2d::prelude::*;
+use bevy_picking_core::backend::prelude::*;
+
+// Re-export for users who want this
+pub use avian2d;
+
+/// Commonly used imports.
+pub mod prelude {
+    pub use crate::{Avian2dBackend, Avian2dBackendSettings, Avian2dPickable};
+}
+
+/// Adds the `avian2d` picking backend to your app.
+#[derive(Clone)]
+pub struct Avian2dBackend;
+impl Plugin for Avian2dBackend {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Avian2dBackendSettings>()
+            .add_systems(PreUpdate, update_hits.in_set(BackendSet::Primary))
+            .register_type::<Avian2dBackendSettings>()
+            .register_type::<Avian2dPickable>();
+    }
+}
+
+/// Runtime settings for the [`Avian2dBackend`].
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource, Default)]
+pub struct Avian2dBackendSettings {
+    /// When set to `true` picking will only happen between cameras and entities marked with
+    /// [`Avian2dPickable`]. Off by default. This setting is provided to give you fine-grained
+    /// control over which cameras and entities should be used by the avian2d backend at runtime.
+    pub require_markers: bool,
+}
+
+/// Optional. Marks cameras and target entities that should be used in the avian2d picking backend.
+/// Only needed if [`Avian2dBackendSettings::require_markers`] is set to true.
+#[derive(Debug, Clone, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct Avian2dPickable;
+
+/// Finds the colliders under each pointer using [`Avian2dBackendSettings`] and the [`RayMap`],
+/// then outputs [`PointerHits`].
+pub fn update_hits(
+    picking_cameras: Query<(&Camera, Option<&Avian2dPickable>, Option<&RenderLayers>)>,
+    ray_map: Res<RayMap>,
+    pickables: Query<&Pickable>,
+    marked_targets: Query<&Avian2dPickable>,
+    layers: Query<&RenderLayers>,
+    transforms: Query<&GlobalTransform>,
+    backend_settings: Res<Avian2dBackendSettings>,
+    spatial_query: Option<Res<SpatialQueryPipeline>>,
+    mut output_events: EventWriter<PointerHits>,
+) {
+    let Some(spatial_query) = spatial_query else {
+        return;
+    };
+
+    for (&ray_id, &ray) in ray_map.map().iter() {
+        let Ok((camera, cam_pickable, cam_layers)) = picking_cameras.get(ray_id.camera) else {
+            continue;
+        };
+        if backend_settings.require_markers && cam_pickable.is_none() || !camera_picks(camera) {
+            continue;
+        }
+
+        let cam_layers = cam_layers.unwrap_or_default();
+
+        // The pointer position in the 2d world, where the ray starts on the near plane.
+        let point = ray.origin.truncate();
+        let mut picks = Vec::new();
+        spatial_query.point_intersections_callback(
+            point,
+            SpatialQueryFilter::default(),
+            |entity| {
+                let marker_requirement =
+                    !backend_settings.require_markers || marked_targets.get(entity).is_ok();
+
+                // Other entities missing render layers are on the default layer 0
+                let entity_layers = layers.get(entity).unwrap_or_default();
+                let render_layers_match = cam_layers.intersects(entity_layers);
+
+                let is_pickable = pickables
+                    .get(entity)
+                    .map(|p| *p != Pickable::IGNORE)
+                    .unwrap_or(true);
+
+                if marker_requirement && render_layers_match && is_pickable {
+                    let z = transforms
+                        .get(entity)
+                        .map(|transform| transform.translation().z)
+                        .unwrap_or_default();
+                    let position = point.extend(z);
+                    let depth = (position - ray.origin).dot(*ray.direction);
+                    let hit_data =
+                        HitData::new(ray_id.camera, depth, Some(position), Some(Vec3::Z));
+                    picks.push((entity, hit_data));
+                }
+                true
+            },
+        );
+
+        if !picks.is_empty() {
+            output_events.send(PointerHits::new(ray_id.pointer, picks, camera.order as f32));
+        }
+    }
+}
@@ -18,7 +18,7 @@ pub use bevy_egui;
 
 /// Commonly used imports for the [`bevy_picking_egui`](crate) crate.
 pub mod prelude {
-    pub use crate::EguiBackend;
+    pub use crate::{EguiBackend, NoEguiPicking};
 }
 
 /// Adds picking support for [`bevy_egui`], by ensuring that egui blocks other entities from being
@@ -32,7 +32,8 @@ impl Plugin for EguiBackend {
             egui_picking,
         )
         .insert_resource(EguiBackendSettings::default())
-        .register_type::<EguiBackendSettings>();
+        .register_type::<EguiBackendSettings>()
+        .register_type::<NoEguiPicking>();
 
         #[cfg(feature = "selection")]
         app.add_systems(First, update_settings);
@@ -48,6 +49,12 @@ pub struct EguiBackendSettings {
     pub allow_deselect: bool,
 }
 
+/// Marks an [`EguiContext`] entity that should be ignored by the [`EguiBackend`], so it does not
+/// block picking of the entities behind it. Useful for debug overlays.
+#[derive(Debug, Clone, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct NoEguiPicking;
+
 /// Marks the entity used as the pseudo egui pointer.
 #[derive(Component, Reflect)]
 pub struct EguiPointer;
@@ -76,7 +83,7 @@ pub fn update_settings(
 /// If egui in the current window is reporting that the pointer is over it, we report a hit.
 pub fn egui_picking(
     pointers: Query<(&PointerId, &PointerLocation)>,
-    mut egui_context: Query<(Entity, &mut EguiContext)>,
+    mut egui_context: Query<(Entity, &mut EguiContext), Without<NoEguiPicking>>,
     mut output: EventWriter<PointerHits>,
 ) {
     for (pointer, location) in pointers
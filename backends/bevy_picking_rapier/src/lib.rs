@@ -47,7 +47,7 @@ pub struct RapierBackend;
 impl Plugin for RapierBackend {
     fn build(&self, app: &mut App) {
         app.init_resource::<RapierBackendSettings>()
-            .add_systems(PreUpdate, update_hits.in_set(PickSet::Backend))
+            .add_systems(PreUpdate, update_hits.in_set(BackendSet::Primary))
             .register_type::<RapierBackendSettings>()
             .register_type::<RapierPickable>();
     }
@@ -89,7 +89,7 @@ pub fn update_hits(
         let Ok((camera, cam_pickable, cam_layers)) = picking_cameras.get(ray_id.camera) else {
             continue;
         };
-        if backend_settings.require_markers && cam_pickable.is_none() {
+        if backend_settings.require_markers && cam_pickable.is_none() || !camera_picks(camera) {
             continue;
         }
 
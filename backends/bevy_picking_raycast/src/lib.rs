@@ -15,19 +15,22 @@
 #![deny(missing_docs)]
 
 use bevy_app::prelude::*;
-use bevy_ecs::prelude::*;
+use bevy_asset::Handle;
+use bevy_ecs::{prelude::*, system::SystemParam};
+use bevy_math::{Ray3d, Vec2};
 use bevy_reflect::prelude::*;
 use bevy_render::{prelude::*, view::RenderLayers};
+use bevy_transform::prelude::*;
 
 use bevy_mod_raycast::prelude::*;
-use bevy_picking_core::backend::prelude::*;
+use bevy_picking_core::backend::{prelude::*, ray};
 
 // Re-export for uses who want this
 pub use bevy_mod_raycast;
 
 /// Commonly used imports for the [`bevy_picking_raycast`](crate) crate.
 pub mod prelude {
-    pub use crate::RaycastBackend;
+    pub use crate::{RaycastBackend, RaycastPicker};
 }
 
 /// Runtime settings for the [`RaycastBackend`].
@@ -41,6 +44,24 @@ pub struct RaycastBackendSettings {
     /// When set to Ignore, hidden items can be raycasted against.
     /// See [`RaycastSettings::visibility`] for more information.
     pub raycast_visibility: RaycastVisibility,
+    /// When set to `true`, the back faces of triangles, facing away from the camera, are ignored
+    /// while raycasting, so entities behind them can be picked. On by default.
+    ///
+    /// Culling is usually what you want for solid objects, as it prevents picking the inside of a
+    /// mesh through a hole in its surface. Disable this if you are picking double-sided geometry,
+    /// like a single quad that can be seen from both sides. This is done by
+    /// [`sync_backface_culling`], which inserts [`NoBackfaceCulling`] on meshes, so culling can
+    /// also be disabled for individual meshes by inserting it yourself.
+    pub backface_culling: bool,
+    /// When set to `true`, raycasting stops at the first entity hit that is hoverable (see
+    /// [`Pickable::is_hoverable`]), in addition to stopping at entities that block lower entities.
+    /// Off by default.
+    ///
+    /// This avoids wasted intersection tests when you only need the nearest entity that can be
+    /// interacted with, in scenes where many non-blocking entities sit in front of it. Note that
+    /// entities behind the first hoverable entity will then never be hovered, even if it does not
+    /// block lower entities.
+    pub stop_at_first_emitter: bool,
 }
 
 impl Default for RaycastBackendSettings {
@@ -48,6 +69,8 @@ impl Default for RaycastBackendSettings {
         Self {
             require_markers: false,
             raycast_visibility: RaycastVisibility::MustBeVisibleAndInView,
+            backface_culling: true,
+            stop_at_first_emitter: false,
         }
     }
 }
@@ -58,39 +81,135 @@ impl Default for RaycastBackendSettings {
 #[reflect(Component, Default)]
 pub struct RaycastPickable;
 
+/// Marks a [`NoBackfaceCulling`] component inserted by [`sync_backface_culling`], which is removed
+/// when [`RaycastBackendSettings::backface_culling`] is enabled again.
+#[derive(Debug, Clone, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct BackfacesFromSettings;
+
 /// Adds the raycasting picking backend to your app.
 #[derive(Clone)]
 pub struct RaycastBackend;
 impl Plugin for RaycastBackend {
     fn build(&self, app: &mut App) {
         app.init_resource::<RaycastBackendSettings>()
-            .add_systems(PreUpdate, update_hits.in_set(PickSet::Backend))
+            .add_systems(
+                PreUpdate,
+                (sync_backface_culling, update_hits)
+                    .chain()
+                    .in_set(BackendSet::Primary),
+            )
             .register_type::<RaycastPickable>()
+            .register_type::<BackfacesFromSettings>()
             .register_type::<RaycastBackendSettings>();
     }
 }
 
+/// Inserts [`NoBackfaceCulling`] on every mesh while [`RaycastBackendSettings::backface_culling`]
+/// is disabled, so `bevy_mod_raycast` includes back faces when raycasting, and removes it once
+/// culling is enabled again.
+pub fn sync_backface_culling(
+    mut commands: Commands,
+    settings: Res<RaycastBackendSettings>,
+    culled: Query<Entity, (With<Handle<Mesh>>, Without<NoBackfaceCulling>)>,
+    unculled: Query<Entity, With<BackfacesFromSettings>>,
+) {
+    if settings.backface_culling {
+        for entity in &unculled {
+            commands
+                .entity(entity)
+                .remove::<(NoBackfaceCulling, BackfacesFromSettings)>();
+        }
+    } else {
+        for entity in &culled {
+            commands
+                .entity(entity)
+                .insert((NoBackfaceCulling, BackfacesFromSettings));
+        }
+    }
+}
+
 /// Raycasts into the scene using [`RaycastBackendSettings`] and [`PointerLocation`]s, then outputs
 /// [`PointerHits`].
 pub fn update_hits(
-    backend_settings: Res<RaycastBackendSettings>,
     ray_map: Res<RayMap>,
-    picking_cameras: Query<(&Camera, Option<&RaycastPickable>, Option<&RenderLayers>)>,
-    pickables: Query<&Pickable>,
-    marked_targets: Query<&RaycastPickable>,
-    layers: Query<&RenderLayers>,
-    mut raycast: Raycast,
+    cameras: Query<&Camera>,
+    mut picker: RaycastPicker,
     mut output_events: EventWriter<PointerHits>,
 ) {
     for (&ray_id, &ray) in ray_map.map().iter() {
-        let Ok((camera, cam_pickable, cam_layers)) = picking_cameras.get(ray_id.camera) else {
+        let Ok(camera) = cameras.get(ray_id.camera) else {
             continue;
         };
-        if backend_settings.require_markers && cam_pickable.is_none() {
+        let Some(picks) = picker.cast_ray(ray_id.camera, ray) else {
             continue;
+        };
+        let order = camera.order as f32;
+        if !picks.is_empty() {
+            output_events.send(PointerHits::new(ray_id.pointer, picks, order));
         }
+    }
+}
+
+/// A system parameter to pick entities at an arbitrary position with the [`RaycastBackend`],
+/// without a pointer.
+///
+/// This is synchronous and side-effect free: no pointer state is changed, and no events are sent.
+/// This can be used to check what is under a screen position right now, for example to validate a
+/// saved click. To use this outside of a system, see
+/// [`SystemState`](bevy_ecs::system::SystemState).
+#[derive(SystemParam)]
+pub struct RaycastPicker<'w, 's> {
+    settings: Res<'w, RaycastBackendSettings>,
+    cameras: Query<
+        'w,
+        's,
+        (
+            &'static Camera,
+            &'static GlobalTransform,
+            Option<&'static RaycastPickable>,
+            Option<&'static RenderLayers>,
+        ),
+    >,
+    pickables: Query<'w, 's, &'static Pickable>,
+    marked_targets: Query<'w, 's, &'static RaycastPickable>,
+    layers: Query<'w, 's, &'static RenderLayers>,
+    raycast: Raycast<'w, 's>,
+}
 
-        let cam_layers = cam_layers.to_owned().unwrap_or_default();
+impl<'w, 's> RaycastPicker<'w, 's> {
+    /// Returns the entities under `viewport_position`, in logical pixels relative to the top left
+    /// of the `camera`'s viewport, sorted from nearest to farthest. This uses the same settings and
+    /// filters as pointers that are picking with the [`RaycastBackend`].
+    ///
+    /// Returns an empty list if the camera does not exist or cannot be used for picking.
+    pub fn pick_at(&mut self, camera: Entity, viewport_position: Vec2) -> Vec<(Entity, HitData)> {
+        let Some(ray) = self
+            .cameras
+            .get(camera)
+            .ok()
+            .and_then(|(cam, transform, ..)| {
+                ray::viewport_to_world(cam, transform, viewport_position)
+            })
+        else {
+            return Vec::new();
+        };
+        self.cast_ray(camera, ray).unwrap_or_default()
+    }
+
+    /// Casts a picking ray from `camera`, returning the sorted hits, or `None` if the camera does
+    /// not exist or cannot be used for picking.
+    fn cast_ray(&mut self, camera: Entity, ray: Ray3d) -> Option<Vec<(Entity, HitData)>> {
+        let (cam, _, cam_pickable, cam_layers) = self.cameras.get(camera).ok()?;
+        if self.settings.require_markers && cam_pickable.is_none() || !camera_picks(cam) {
+            return None;
+        }
+
+        let cam_layers = cam_layers.cloned().unwrap_or_default();
+        let backend_settings = &self.settings;
+        let pickables = &self.pickables;
+        let marked_targets = &self.marked_targets;
+        let layers = &self.layers;
 
         let settings = RaycastSettings {
             visibility: backend_settings.raycast_visibility,
@@ -109,28 +228,112 @@ pub fn update_hits(
 
                 marker_requirement && render_layers_match && is_pickable
             },
-            early_exit_test: &|entity_hit| {
-                pickables
-                    .get(entity_hit)
-                    .is_ok_and(|pickable| pickable.should_block_lower)
+            early_exit_test: &|entity_hit| match pickables.get(entity_hit) {
+                Ok(pickable) => {
+                    pickable.should_block_lower
+                        || (backend_settings.stop_at_first_emitter && pickable.is_hoverable)
+                }
+                // Entities without `Pickable` are hoverable by default.
+                Err(_) => backend_settings.stop_at_first_emitter,
             },
         };
-        let picks = raycast
+        let picks = self
+            .raycast
             .cast_ray(ray, &settings)
             .iter()
             .map(|(entity, hit)| {
-                let hit_data = HitData::new(
-                    ray_id.camera,
+                let mut hit_data = HitData::new(
+                    camera,
                     hit.distance(),
                     Some(hit.position()),
                     Some(hit.normal()),
                 );
+                if let Some(triangle_index) = hit.triangle_index() {
+                    hit_data = hit_data.with_triangle_index(triangle_index);
+                }
                 (*entity, hit_data)
             })
-            .collect::<Vec<_>>();
-        let order = camera.order as f32;
-        if !picks.is_empty() {
-            output_events.send(PointerHits::new(ray_id.pointer, picks, order));
-        }
+            .collect();
+        Some(picks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use bevy_asset::Assets;
+    use bevy_ecs::system::{RunSystemOnce, SystemState};
+    use bevy_math::{primitives::Rectangle, Quat, Vec3};
+    use bevy_render::mesh::Meshable;
+
+    use super::*;
+
+    /// Casts a ray through two blocking quads, the nearer of which shows its back face to the ray.
+    /// Returns the entities that were hit, and the nearer and farther quad.
+    fn cast_through_quads(backface_culling: bool) -> (Vec<Entity>, Entity, Entity) {
+        let mut world = World::new();
+        world.insert_resource(RaycastBackendSettings {
+            backface_culling,
+            raycast_visibility: RaycastVisibility::Ignore,
+            ..Default::default()
+        });
+        let mesh = Rectangle::new(1.0, 1.0).mesh().build();
+        let aabb = mesh.compute_aabb().unwrap();
+        world.init_resource::<Assets<Mesh>>();
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(mesh);
+        let mut spawn_quad = |transform: Transform| {
+            world
+                .spawn((
+                    mesh.clone(),
+                    aabb,
+                    GlobalTransform::from(transform),
+                    InheritedVisibility::VISIBLE,
+                    ViewVisibility::default(),
+                    Pickable::default(),
+                ))
+                .id()
+        };
+        let near =
+            spawn_quad(Transform::from_xyz(0.0, 0.0, 1.0).with_rotation(Quat::from_rotation_y(PI)));
+        let far = spawn_quad(Transform::IDENTITY);
+        let camera = world
+            .spawn((Camera::default(), GlobalTransform::default()))
+            .id();
+        world.run_system_once(sync_backface_culling);
+
+        let ray = Ray3d::new(Vec3::Z * 10.0, Vec3::NEG_Z);
+        let mut picker = SystemState::<RaycastPicker>::new(&mut world);
+        let picks = picker.get_mut(&mut world).cast_ray(camera, ray).unwrap();
+        let hits = picks.into_iter().map(|(entity, _)| entity).collect();
+        (hits, near, far)
+    }
+
+    #[test]
+    fn culled_back_face_does_not_block() {
+        let (hits, _, far) = cast_through_quads(true);
+        assert_eq!(hits, vec![far]);
+    }
+
+    #[test]
+    fn back_face_is_picked_without_culling() {
+        let (hits, near, _) = cast_through_quads(false);
+        assert_eq!(hits, vec![near]);
+    }
+
+    #[test]
+    fn inactive_camera_does_not_pick() {
+        let mut world = World::new();
+        world.init_resource::<RaycastBackendSettings>();
+        world.init_resource::<Assets<Mesh>>();
+        let camera = Camera {
+            is_active: false,
+            ..Default::default()
+        };
+        let camera = world.spawn((camera, GlobalTransform::default())).id();
+
+        let ray = Ray3d::new(Vec3::Z * 10.0, Vec3::NEG_Z);
+        let mut picker = SystemState::<RaycastPicker>::new(&mut world);
+        assert_eq!(picker.get_mut(&mut world).cast_ray(camera, ray), None);
     }
 }
@@ -10,8 +10,9 @@ use bevy_app::prelude::*;
 use bevy_asset::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_math::prelude::*;
-use bevy_render::prelude::*;
-use bevy_sprite::{Sprite, TextureAtlas, TextureAtlasLayout};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{prelude::*, view::RenderLayers};
+use bevy_sprite::{ImageScaleMode, Sprite, TextureAtlas, TextureAtlasLayout};
 use bevy_transform::prelude::*;
 use bevy_window::PrimaryWindow;
 
@@ -19,7 +20,7 @@ use bevy_picking_core::backend::prelude::*;
 
 /// Commonly used imports for the [`bevy_picking_sprite`](crate) crate.
 pub mod prelude {
-    pub use crate::SpriteBackend;
+    pub use crate::{SpriteBackend, SpriteBackendSettings, SpritePickingOrder, SpriteSortKey};
 }
 
 /// Adds picking support for [`bevy_sprite`].
@@ -28,15 +29,67 @@ pub struct SpriteBackend;
 
 impl Plugin for SpriteBackend {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreUpdate, sprite_picking.in_set(PickSet::Backend));
+        app.init_resource::<SpriteBackendSettings>()
+            .add_systems(PreUpdate, sprite_picking.in_set(BackendSet::Primary))
+            .register_type::<SpriteBackendSettings>()
+            .register_type::<SpriteSortKey>();
     }
 }
 
+/// Runtime settings for the [`SpriteBackend`].
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource, Default)]
+pub struct SpriteBackendSettings {
+    /// Determines which sprite is on top when sprites overlap. This should match the order your
+    /// app draws sprites in, so the sprite that is picked is the one that is visible.
+    pub sort_key: SpritePickingOrder,
+}
+
+/// The order sprites are picked in, from top to bottom. See [`SpriteBackendSettings::sort_key`].
+///
+/// With any order other than [`SpritePickingOrder::ZTranslation`], each reported [`HitData`] is
+/// placed in its own [`HitData::layer`], just above the order of the camera, following the position
+/// of the sprite in this order. The focus step then picks the same sprite that is drawn on top,
+/// while [`HitData::depth`] is still the distance from the camera. Note that this places these
+/// sprites above the hits of other backends using the same camera, like meshes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum SpritePickingOrder {
+    /// Sprites with a greater Z translation are on top. This matches how `bevy` draws sprites.
+    #[default]
+    ZTranslation,
+    /// Sprites with a lower Y translation are on top, and sprites with the same Y translation are
+    /// ordered by Z translation. This matches the draw order of y-sorted top-down 2d games.
+    YThenZ,
+    /// Sprites with a greater [`SpriteSortKey`] are on top. Sprites without the component use
+    /// their Z translation as the key, and sprites with the same key are ordered by Z translation.
+    Custom,
+}
+
+/// The key used to order overlapping sprites when [`SpriteBackendSettings::sort_key`] is
+/// [`SpritePickingOrder::Custom`]. Sprites with a greater key are picked first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct SpriteSortKey(pub f32);
+
+/// The range of [`HitData::layer`]s above the camera order used to keep the draw order of sprites,
+/// see [`SpritePickingOrder`]. This stays below the default order offset of the `bevy_ui` backend,
+/// `0.5`. If the `order_offset` of the `bevy_ui` backend is set to this span or lower, sprites can
+/// be picked above UI drawn by the same camera, so the docs of that setting must be kept in sync.
+const SPRITE_LAYERS_SPAN: f32 = 0.25;
+
 /// Checks if any sprite entities are under each pointer
 pub fn sprite_picking(
     pointers: Query<(&PointerId, &PointerLocation)>,
-    cameras: Query<(Entity, &Camera, &GlobalTransform, &OrthographicProjection)>,
+    cameras: Query<(
+        Entity,
+        &Camera,
+        &GlobalTransform,
+        &OrthographicProjection,
+        Option<&RenderLayers>,
+    )>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
+    settings: Res<SpriteBackendSettings>,
+    touch_settings: Res<TouchPickSettings>,
     images: Res<Assets<Image>>,
     texture_atlas_layout: Res<Assets<TextureAtlasLayout>>,
     sprite_query: Query<
@@ -47,76 +100,151 @@ pub fn sprite_picking(
             Option<&Handle<Image>>,
             &GlobalTransform,
             Option<&Pickable>,
+            Option<&PickTolerance>,
+            Option<&ImageScaleMode>,
+            Option<&RenderLayers>,
             &ViewVisibility,
+            Option<&SpriteSortKey>,
         ),
         Or<(With<Sprite>, With<TextureAtlas>)>,
     >,
     mut output: EventWriter<PointerHits>,
 ) {
+    // The key of each sprite, sprites with a greater key are on top.
+    let sort_key = |transform: &GlobalTransform, key: Option<&SpriteSortKey>| {
+        let translation = transform.translation();
+        match settings.sort_key {
+            SpritePickingOrder::ZTranslation => (translation.z, 0.0),
+            SpritePickingOrder::YThenZ => (-translation.y, translation.z),
+            SpritePickingOrder::Custom => (key.map_or(translation.z, |k| k.0), translation.z),
+        }
+    };
     let mut sorted_sprites: Vec<_> = sprite_query.iter().collect();
     sorted_sprites.sort_by(|a, b| {
-        (b.4.translation().z)
-            .partial_cmp(&a.4.translation().z)
+        sort_key(b.4, b.10)
+            .partial_cmp(&sort_key(a.4, a.10))
             .unwrap_or(Ordering::Equal)
     });
+    let sprite_count = sorted_sprites.len();
 
     for (pointer, location) in pointers.iter().filter_map(|(pointer, pointer_location)| {
         pointer_location.location().map(|loc| (pointer, loc))
     }) {
         let mut blocked = false;
-        let Some((cam_entity, camera, cam_transform, cam_ortho)) = cameras
+        // Only the camera whose viewport contains the pointer can pick, so split-screen viewports
+        // don't pick sprites drawn by each other's cameras.
+        let Some((cam_entity, camera, cam_transform, cam_ortho, cam_layers)) = cameras
             .iter()
-            .filter(|(_, camera, _, _)| camera.is_active)
-            .find(|(_, camera, _, _)| {
-                camera
-                    .target
-                    .normalize(Some(match primary_window.get_single() {
-                        Ok(w) => w,
-                        Err(_) => return false,
-                    }))
-                    .unwrap()
-                    == location.target
-            })
+            .filter(|(_, camera, ..)| camera_picks(camera))
+            .find(|(_, camera, ..)| location.is_in_viewport(camera, &primary_window))
         else {
             continue;
         };
 
-        let Some(cursor_pos_world) = camera.viewport_to_world_2d(cam_transform, location.position)
+        // The pointer position relative to the camera's viewport
+        let mut viewport_pos = location.position;
+        if let Some(viewport) = &camera.viewport {
+            let Some(viewport_logical) = camera.to_logical(viewport.physical_position) else {
+                continue;
+            };
+            viewport_pos -= viewport_logical;
+        }
+
+        let Some(cursor_pos_world) = camera.viewport_to_world_2d(cam_transform, viewport_pos)
         else {
             continue;
         };
+        // Converts a world space position to the window coordinates of the pointer location.
+        let world_to_screen = |world_pos: Vec3| {
+            let pos = camera.world_to_viewport(cam_transform, world_pos)?;
+            Some(pos + location.position - viewport_pos)
+        };
+        // The size of a logical pixel in world space, used to convert the `PickTolerance`.
+        let world_per_px = camera
+            .viewport_to_world_2d(cam_transform, viewport_pos + Vec2::X)
+            .map(|pos| pos.distance(cursor_pos_world))
+            .unwrap_or(1.0);
 
-        let picks: Vec<(Entity, HitData)> = sorted_sprites
+        let cam_layers = cam_layers.cloned().unwrap_or_default();
+        let order = camera.order as f32;
+
+        // For touch pointers, the sprite nearest to the touch within
+        // `TouchPickSettings::hit_radius_px`, used if no sprite is directly under the touch.
+        let touch_radius = match pointer.is_touch() {
+            true => touch_settings.hit_radius_px * world_per_px,
+            false => 0.0,
+        };
+        let mut nearest: Option<(f32, (Entity, HitData))> = None;
+
+        let mut picks: Vec<(Entity, HitData)> = sorted_sprites
             .iter()
             .copied()
-            .filter(|(.., visibility)| visibility.get())
+            .enumerate()
+            .filter(|(_, (.., visibility, _))| visibility.get())
+            .filter(|(_, (.., layers, _, _))| {
+                // Sprites missing render layers are on the default layer 0
+                cam_layers.intersects(&layers.cloned().unwrap_or_default())
+            })
             .filter_map(
-                |(entity, sprite, atlas, image, sprite_transform, pickable, ..)| {
+                |(
+                    draw_rank,
+                    (
+                        entity,
+                        sprite,
+                        atlas,
+                        image,
+                        sprite_transform,
+                        pickable,
+                        tolerance,
+                        scale_mode,
+                        ..,
+                    ),
+                )| {
                     if blocked {
                         return None;
                     }
 
-                    // Hit box in sprite coordinate system
-                    let (extents, anchor) = if let Some((sprite, atlas)) = sprite.zip(atlas) {
-                        let extents = sprite.custom_size.or_else(|| {
-                            texture_atlas_layout
-                                .get(&atlas.layout)
-                                .map(|f| f.textures[atlas.index].size().as_vec2())
-                        })?;
-                        let anchor = sprite.anchor.as_vec();
-                        (extents, anchor)
-                    } else if let Some((sprite, image)) = sprite.zip(image) {
-                        let extents = sprite
-                            .custom_size
-                            .or_else(|| images.get(image).map(|f| f.size().as_vec2()))?;
-                        let anchor = sprite.anchor.as_vec();
-                        (extents, anchor)
-                    } else {
-                        return None;
+                    // Hit box in sprite coordinate system, using the size the sprite is drawn at.
+                    // Sliced sprites are drawn at the size of their texture region, like other
+                    // sprites, but tiled sprites are drawn at the size of the whole image.
+                    let sprite = sprite?;
+                    let extents = match sprite.custom_size {
+                        Some(custom_size) => custom_size,
+                        None => {
+                            let (region_size, image_size) = if let Some(atlas) = atlas {
+                                let layout = texture_atlas_layout.get(&atlas.layout)?;
+                                let region = layout.textures.get(atlas.index)?;
+                                (region.size().as_vec2(), layout.size.as_vec2())
+                            } else {
+                                let image_size = images.get(image?)?.size().as_vec2();
+                                let region_size = sprite.rect.map_or(image_size, |r| r.size());
+                                (region_size, image_size)
+                            };
+                            match scale_mode {
+                                Some(ImageScaleMode::Tiled { .. }) => image_size,
+                                _ => region_size,
+                            }
+                        }
+                    };
+                    let anchor = sprite.anchor.as_vec();
+
+                    // Converts a world space distance to the sprite coordinate system
+                    let to_sprite_space = |world_distance: f32| {
+                        sprite_transform
+                            .affine()
+                            .inverse()
+                            .transform_vector3(Vec3::X * world_distance)
+                            .length()
                     };
 
+                    // The tolerance, converted from screen space to the sprite coordinate system
+                    let tolerance = tolerance.map_or(0.0, |tolerance| {
+                        to_sprite_space(tolerance.screen_radius_px * world_per_px)
+                    });
+
                     let center = -anchor * extents;
-                    let rect = Rect::from_center_half_size(center, extents / 2.0);
+                    let rect =
+                        Rect::from_center_half_size(center, extents / 2.0).inflate(tolerance);
 
                     // Transform cursor pos to sprite coordinate system
                     let cursor_pos_sprite = sprite_transform
@@ -124,20 +252,303 @@ pub fn sprite_picking(
                         .inverse()
                         .transform_point3((cursor_pos_world, 0.0).into());
 
-                    let is_cursor_in_sprite = rect.contains(cursor_pos_sprite.truncate());
+                    let cursor = cursor_pos_sprite.truncate();
+                    let is_cursor_in_sprite = rect.contains(cursor);
                     blocked = is_cursor_in_sprite
                         && pickable.map(|p| p.should_block_lower) != Some(false);
 
+                    // Sprites outside of the touch radius are not tested any further.
+                    let is_near_touch = !is_cursor_in_sprite && touch_radius > 0.0 && {
+                        let outside = (rect.min - cursor).max(cursor - rect.max).max(Vec2::ZERO);
+                        outside.length() <= to_sprite_space(touch_radius)
+                    };
+                    if !is_cursor_in_sprite && !is_near_touch {
+                        return None;
+                    }
+
                     // HitData requires a depth as calculated from the camera's near clipping plane
-                    let depth = -cam_ortho.near - sprite_transform.translation().z;
+                    let depth = (sprite_transform.translation() - cam_transform.translation())
+                        .dot(*cam_transform.forward())
+                        - cam_ortho.near;
 
-                    is_cursor_in_sprite
-                        .then_some((entity, HitData::new(cam_entity, depth, None, None)))
+                    // The cursor position projected onto the plane of the sprite, in world space
+                    let position = cursor_pos_world.extend(sprite_transform.translation().z);
+                    let mut hit_data =
+                        HitData::new(cam_entity, depth, Some(position), Some(Vec3::Z));
+                    // When sprites are not ordered by their distance, the draw order is kept by
+                    // giving each sprite a layer between the camera order and the bevy ui order
+                    // offset, from the top sprite down.
+                    if settings.sort_key != SpritePickingOrder::ZTranslation {
+                        let rank_from_bottom = (sprite_count - draw_rank) as f32;
+                        hit_data = hit_data.with_layer(
+                            order + SPRITE_LAYERS_SPAN * rank_from_bottom / sprite_count as f32,
+                        );
+                    }
+                    // The bounds of the sprite on screen, around all of its corners.
+                    let corners = [
+                        rect.min,
+                        rect.max,
+                        rect.min.with_y(rect.max.y),
+                        rect.max.with_y(rect.min.y),
+                    ]
+                    .map(|corner| {
+                        world_to_screen(sprite_transform.transform_point(corner.extend(0.0)))
+                    });
+                    if let [Some(a), Some(b), Some(c), Some(d)] = corners {
+                        hit_data = hit_data
+                            .with_bounds(Rect::from_corners(a, b).union_point(c).union_point(d));
+                    }
+                    let hit = (entity, hit_data);
+
+                    if is_near_touch {
+                        let center_distance = sprite_transform
+                            .transform_point(center.extend(0.0))
+                            .truncate()
+                            .distance(cursor_pos_world);
+                        let is_nearest = match &nearest {
+                            Some((nearest_distance, _)) => center_distance < *nearest_distance,
+                            None => true,
+                        };
+                        if is_nearest {
+                            nearest = Some((center_distance, hit));
+                        }
+                        return None;
+                    }
+
+                    Some(hit)
                 },
             )
             .collect();
 
-        let order = camera.order as f32;
+        if picks.is_empty() {
+            picks.extend(nearest.map(|(_, hit)| hit));
+        }
+
         output.send(PointerHits::new(*pointer, picks, order));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_picking_core::{pointer::Location, CorePlugin, PointerCoreBundle};
+    use bevy_render::{
+        camera::{camera_system, ManualTextureViews, RenderTarget, Viewport},
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        view::ViewVisibility,
+    };
+    use bevy_window::{Window, WindowCreated, WindowRef, WindowResized, WindowScaleFactorChanged};
+
+    use super::*;
+
+    /// An app with the sprite backend, a 100x100 primary window, and a mouse pointer in it.
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, SpriteBackend))
+            .init_resource::<Assets<Image>>()
+            .init_resource::<Assets<TextureAtlasLayout>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_event::<AssetEvent<Image>>();
+        let mut window = Window::default();
+        window.resolution.set(100.0, 100.0);
+        let window = app.world_mut().spawn((window, PrimaryWindow)).id();
+        let target = RenderTarget::Window(WindowRef::Primary)
+            .normalize(Some(window))
+            .unwrap();
+        app.world_mut().spawn(
+            PointerCoreBundle::new(PointerId::Mouse).with_location(Location {
+                target,
+                position: Vec2::splat(50.0),
+            }),
+        );
+        app
+    }
+
+    /// Spawns a 2d camera, looking at `position`.
+    fn spawn_camera(app: &mut App, camera: Camera, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                camera,
+                OrthographicProjection::default(),
+                GlobalTransform::from_translation(position.extend(100.0)),
+            ))
+            .id()
+    }
+
+    /// Spawns a visible sprite at `position`.
+    fn spawn_sprite(app: &mut App, sprite: impl Bundle, position: Vec2) -> Entity {
+        let mut visibility = ViewVisibility::default();
+        visibility.set();
+        app.world_mut()
+            .spawn((
+                sprite,
+                GlobalTransform::from_translation(position.extend(0.0)),
+                visibility,
+            ))
+            .id()
+    }
+
+    /// Moves the pointer to `position` in the window, runs the app, and returns the entities hit,
+    /// with the camera that hit them.
+    fn pick_at(app: &mut App, position: Vec2) -> Vec<(Entity, Entity)> {
+        let world = app.world_mut();
+        world.run_system_once(camera_system::<OrthographicProjection>);
+        let mut pointer = world.query::<&mut PointerLocation>();
+        let mut pointer = pointer.single_mut(world);
+        pointer.location.as_mut().unwrap().position = position;
+        app.update();
+        app.world()
+            .resource::<Events<PointerHits>>()
+            .iter_current_update_events()
+            .flat_map(|hits| hits.picks.iter())
+            .map(|(entity, hit)| (*entity, hit.camera))
+            .collect()
+    }
+
+    fn sized_sprite(size: f32) -> Sprite {
+        Sprite {
+            custom_size: Some(Vec2::splat(size)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn inactive_camera_does_not_pick() {
+        let mut app = test_app();
+        let camera = spawn_camera(&mut app, Camera::default(), Vec2::ZERO);
+        let sprite = spawn_sprite(&mut app, sized_sprite(10.0), Vec2::ZERO);
+        assert_eq!(pick_at(&mut app, Vec2::splat(50.0)), vec![(sprite, camera)]);
+
+        app.world_mut().get_mut::<Camera>(camera).unwrap().is_active = false;
+        assert_eq!(pick_at(&mut app, Vec2::splat(50.0)), vec![]);
+    }
+
+    #[test]
+    fn split_screen_does_not_pick_other_viewport() {
+        let mut app = test_app();
+        let viewport = |x| Viewport {
+            physical_position: UVec2::new(x, 0),
+            physical_size: UVec2::new(50, 100),
+            ..Default::default()
+        };
+        let left_camera = Camera {
+            viewport: Some(viewport(0)),
+            ..Default::default()
+        };
+        let right_camera = Camera {
+            viewport: Some(viewport(50)),
+            order: 1,
+            ..Default::default()
+        };
+        spawn_camera(&mut app, right_camera, Vec2::X * 1000.0);
+        let left = spawn_camera(&mut app, left_camera, Vec2::ZERO);
+        // The pointer is 20 pixels right of the center of the left viewport. Without the viewport
+        // check, the right camera would see it 30 pixels left of the center of its view.
+        let left_sprite = spawn_sprite(&mut app, sized_sprite(10.0), Vec2::X * 20.0);
+        spawn_sprite(&mut app, sized_sprite(20.0), Vec2::X * 970.0);
+
+        assert_eq!(
+            pick_at(&mut app, Vec2::new(45.0, 50.0)),
+            vec![(left_sprite, left)]
+        );
+    }
+
+    #[test]
+    fn bounds_are_in_window_coordinates() {
+        let mut app = test_app();
+        let camera = Camera {
+            viewport: Some(Viewport {
+                physical_position: UVec2::new(50, 0),
+                physical_size: UVec2::new(50, 100),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        spawn_camera(&mut app, camera, Vec2::ZERO);
+        spawn_sprite(&mut app, sized_sprite(10.0), Vec2::ZERO);
+        assert_eq!(pick_at(&mut app, Vec2::new(75.0, 50.0)).len(), 1);
+
+        let hits = app.world().resource::<Events<PointerHits>>();
+        let (_, hit) = &hits.iter_current_update_events().next().unwrap().picks[0];
+        assert_eq!(hit.bounds, Some(Rect::new(70.0, 45.0, 80.0, 55.0)));
+    }
+
+    /// Returns `true` if a sprite at the center of the screen, built by `sprite` with a 40x40
+    /// image, is hit `offset` pixels to the right of its center.
+    fn is_hit(sprite: impl FnOnce(&mut App, Handle<Image>) -> Entity, offset: f32) -> bool {
+        let mut app = test_app();
+        spawn_camera(&mut app, Camera::default(), Vec2::ZERO);
+        let image = Image::new_fill(
+            Extent3d {
+                width: 40,
+                height: 40,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255; 4],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let image = app.world_mut().resource_mut::<Assets<Image>>().add(image);
+        let sprite = sprite(&mut app, image);
+        pick_at(&mut app, Vec2::new(50.0 + offset, 50.0))
+            .iter()
+            .any(|(entity, _)| *entity == sprite)
+    }
+
+    #[test]
+    fn sprite_extents() {
+        let region = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let image_sprite = |sprite: Sprite| {
+            move |app: &mut App, image| spawn_sprite(app, (sprite, image), Vec2::ZERO)
+        };
+
+        // The custom size overrides the size of the image.
+        assert!(is_hit(image_sprite(sized_sprite(60.0)), 25.0));
+        assert!(!is_hit(image_sprite(sized_sprite(20.0)), 15.0));
+
+        // Without a custom size, sprites are drawn at the size of the image, or of its region.
+        assert!(is_hit(image_sprite(Sprite::default()), 15.0));
+        let rect_sprite = Sprite {
+            rect: Some(region),
+            ..Default::default()
+        };
+        assert!(is_hit(image_sprite(rect_sprite.clone()), 4.0));
+        assert!(!is_hit(image_sprite(rect_sprite.clone()), 15.0));
+        let atlas_sprite = |app: &mut App, image| {
+            let mut layout = TextureAtlasLayout::new_empty(UVec2::splat(40));
+            let index = layout.add_texture(URect::new(0, 0, 10, 10));
+            let layout = app
+                .world_mut()
+                .resource_mut::<Assets<TextureAtlasLayout>>()
+                .add(layout);
+            let atlas = TextureAtlas { layout, index };
+            spawn_sprite(app, (Sprite::default(), image, atlas), Vec2::ZERO)
+        };
+        assert!(is_hit(atlas_sprite, 4.0));
+        assert!(!is_hit(atlas_sprite, 15.0));
+
+        // Sliced sprites are drawn at the size of their region, but tiled sprites are drawn at the
+        // size of the whole image.
+        let scaled_sprite = |sprite: Sprite, scale_mode: ImageScaleMode| {
+            move |app: &mut App, image| spawn_sprite(app, (sprite, image, scale_mode), Vec2::ZERO)
+        };
+        let sliced = ImageScaleMode::Sliced(Default::default());
+        assert!(!is_hit(
+            scaled_sprite(rect_sprite.clone(), sliced.clone()),
+            15.0
+        ));
+        assert!(is_hit(scaled_sprite(sized_sprite(60.0), sliced), 25.0));
+        let tiled = ImageScaleMode::Tiled {
+            tile_x: true,
+            tile_y: true,
+            stretch_value: 1.0,
+        };
+        assert!(is_hit(scaled_sprite(rect_sprite, tiled.clone()), 15.0));
+        assert!(is_hit(scaled_sprite(sized_sprite(60.0), tiled), 25.0));
+    }
+}
@@ -7,16 +7,25 @@
 //!
 //! ## Important Note
 //!
-//! This backend completely ignores [`FocusPolicy`](bevy_ui::FocusPolicy). The design of bevy ui's
-//! focus systems and the picking plugin are not compatible. Instead, use the [`Pickable`] component
-//! to customize how an entity responds to picking focus.
+//! By default, this backend completely ignores [`FocusPolicy`]. The design of bevy ui's focus
+//! systems and the picking plugin are not compatible. Instead, use the [`Pickable`] component to
+//! customize how an entity responds to picking focus.
+//!
+//! To ease migration of existing UIs, [`BevyUiBackendSettings::respect_focus_policy`] can be
+//! enabled, so that nodes without a [`Pickable`] component are given one matching their
+//! [`FocusPolicy`], which decides whether they block lower nodes.
 //!
 //! ## Implementation Notes
 //!
 //! - Bevy ui can only render to the primary window
 //! - Bevy ui can render on any camera with a flag, it is special, and is not tied to a particular
 //!   camera.
-//! - To correctly sort picks, the order of bevy UI is set to be the camera order plus 0.5.
+//! - Bevy ui does not use [`RenderLayers`](bevy_render::view::RenderLayers) to decide which camera
+//!   draws a node, only [`TargetCamera`], so render layers are ignored by this backend as well.
+//! - To correctly sort picks, the order of bevy UI is set to be the camera order plus
+//!   [`BevyUiBackendSettings::order_offset`], `0.5` by default.
+//! - If [`BevyUiBackendSettings::occlude_other_backends`] is enabled, the order of bevy UI is
+//!   instead set to [`BevyUiBackendSettings::OCCLUDING_ORDER`], so that UI blocks other backends.
 
 #![allow(clippy::type_complexity)]
 #![allow(clippy::too_many_arguments)]
@@ -24,18 +33,28 @@
 
 use bevy_app::prelude::*;
 use bevy_ecs::{prelude::*, query::QueryData};
-use bevy_math::Vec2;
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::prelude::*;
 use bevy_render::prelude::*;
 use bevy_transform::prelude::*;
-use bevy_ui::{prelude::*, RelativeCursorPosition, UiStack};
-use bevy_utils::hashbrown::HashMap;
+use bevy_ui::{prelude::*, FocusPolicy, RelativeCursorPosition, UiStack};
+use bevy_utils::hashbrown::{HashMap, HashSet};
 use bevy_window::PrimaryWindow;
 
-use bevy_picking_core::backend::prelude::*;
+use bevy_picking_core::{
+    backend::prelude::*,
+    events::{ClampDragToEntity, DragBounds},
+};
+
+#[cfg(feature = "text")]
+pub mod text;
 
 /// Commonly used imports for the [`bevy_picking_ui`](crate) crate.
 pub mod prelude {
-    pub use crate::BevyUiBackend;
+    pub use crate::{BevyUiBackend, BevyUiBackendSettings, PickableFromFocusPolicy};
+
+    #[cfg(feature = "text")]
+    pub use crate::text::HoveredTextSection;
 }
 
 /// Adds picking support for [`bevy_ui`].
@@ -43,10 +62,80 @@ pub mod prelude {
 pub struct BevyUiBackend;
 impl Plugin for BevyUiBackend {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreUpdate, ui_picking.in_set(PickSet::Backend));
+        app.init_resource::<BevyUiBackendSettings>()
+            .add_systems(
+                PreUpdate,
+                (
+                    sync_focus_policy.before(ui_picking),
+                    ui_picking,
+                    update_drag_bounds,
+                )
+                    .in_set(BackendSet::Primary),
+            )
+            .register_type::<BevyUiBackendSettings>()
+            .register_type::<PickableFromFocusPolicy>();
+
+        #[cfg(feature = "text")]
+        app.add_systems(
+            PreUpdate,
+            text::update_hovered_text_sections.in_set(PickSet::PostFocus),
+        )
+        .register_type::<text::HoveredTextSection>();
+    }
+}
+
+/// Runtime settings for the [`BevyUiBackend`].
+#[derive(Resource, Reflect)]
+#[reflect(Resource, Default)]
+pub struct BevyUiBackendSettings {
+    /// When set to `true`, UI hits are placed above the hits of all other backends, regardless of
+    /// camera order. Any UI node under a pointer that blocks lower entities (see
+    /// [`Pickable::should_block_lower`]) will then prevent entities picked by other backends, like
+    /// a 3d scene behind the UI, from being hovered. Off by default.
+    ///
+    /// This is useful to stop clicks on UI from also clicking the world behind it.
+    pub occlude_other_backends: bool,
+    /// When set to `true`, nodes without a [`Pickable`] component use their [`FocusPolicy`] to
+    /// decide if they block lower nodes: [`FocusPolicy::Block`] blocks, like
+    /// [`Pickable::should_block_lower`], and [`FocusPolicy::Pass`] does not. This is done by
+    /// [`sync_focus_policy`], which inserts a matching [`Pickable`]. Off by default.
+    ///
+    /// This is intended as a compatibility mode when migrating from bevy ui's `Interaction`. If a
+    /// node has a [`Pickable`] component of its own, it always takes precedence.
+    pub respect_focus_policy: bool,
+    /// The [`HitData::depth`] added for each node below the topmost hit node, used to keep hits in
+    /// the order of the [`UiStack`]. UI is drawn on the near plane, so this should be small enough
+    /// that the UI stays close to a depth of `0.0`, but large enough to stay distinct from the
+    /// previous depth. Defaults to `0.00001`.
+    pub depth_step: f32,
+    /// Added to the camera order to get the [`PointerHits::order`] of UI hits, so that UI is above
+    /// the other backends using the same camera, which report hits at the camera order. It is below
+    /// the next camera, as long as it is less than `1.0`. Backends using fractional orders of their
+    /// own should pick an offset that does not collide with them. The sprite backend places sprites
+    /// up to `0.25` above the camera order when it keeps their draw order, so the offset must be
+    /// greater than `0.25` for UI to stay above sprites. Ignored when
+    /// [`BevyUiBackendSettings::occlude_other_backends`] is enabled. Defaults to `0.5`.
+    pub order_offset: f32,
+}
+
+impl Default for BevyUiBackendSettings {
+    fn default() -> Self {
+        Self {
+            occlude_other_backends: false,
+            respect_focus_policy: false,
+            depth_step: 0.00001,
+            order_offset: 0.5,
+        }
     }
 }
 
+impl BevyUiBackendSettings {
+    /// The [`PointerHits::order`] used for UI hits when
+    /// [`BevyUiBackendSettings::occlude_other_backends`] is enabled. This is just below the order
+    /// used by the `egui` backend, so egui will still be on top of bevy ui.
+    pub const OCCLUDING_ORDER: f32 = 999_999.0;
+}
+
 /// Main query from bevy's `ui_focus_system`
 #[derive(QueryData)]
 #[query_data(mutable)]
@@ -56,6 +145,8 @@ pub struct NodeQuery {
     global_transform: &'static GlobalTransform,
     relative_cursor_position: Option<&'static mut RelativeCursorPosition>,
     pickable: Option<&'static Pickable>,
+    interaction: Has<Interaction>,
+    pick_tolerance: Option<&'static PickTolerance>,
     calculated_clip: Option<&'static CalculatedClip>,
     view_visibility: Option<&'static ViewVisibility>,
     target_camera: Option<&'static TargetCamera>,
@@ -66,6 +157,8 @@ pub struct NodeQuery {
 /// Bevy's [`UiStack`] orders all nodes in the order they will be rendered, which is the same order
 /// we need for determining picking.
 pub fn ui_picking(
+    settings: Res<BevyUiBackendSettings>,
+    touch_settings: Res<TouchPickSettings>,
     pointers: Query<(&PointerId, &PointerLocation)>,
     camera_query: Query<(Entity, &Camera, Has<IsDefaultUiCamera>)>,
     default_ui_camera: DefaultUiCamera,
@@ -84,27 +177,15 @@ pub fn ui_picking(
         })
     {
         // This pointer is associated with a render target, which could be used by multiple
-        // cameras. We want to ensure we return all cameras with a matching target.
-        for camera in camera_query
+        // cameras. We want to ensure we return all active cameras with a matching target, whose
+        // viewport contains the pointer, so split-screen viewports don't pick each other's UI.
+        for (camera, camera_data, _) in camera_query
             .iter()
-            .map(|(entity, camera, _)| {
-                (
-                    entity,
-                    camera.target.normalize(primary_window.get_single().ok()),
-                )
-            })
-            .filter_map(|(entity, target)| Some(entity).zip(target))
-            .filter(|(_entity, target)| target == &pointer_location.target)
-            .map(|(cam_entity, _target)| cam_entity)
+            // Inactive cameras do not draw any UI, so they should not pick it either.
+            .filter(|(_, camera, _)| camera_picks(camera))
+            .filter(|(_, camera, _)| pointer_location.is_in_viewport(camera, &primary_window))
         {
-            let Ok((_, camera_data, _)) = camera_query.get(camera) else {
-                continue;
-            };
-            let mut pointer_pos = pointer_location.position;
-            if let Some(viewport) = camera_data.logical_viewport_rect() {
-                pointer_pos -= viewport.min;
-            }
-            let scaled_pointer_pos = pointer_pos / **ui_scale;
+            let scaled_pointer_pos = ui_position(&pointer_location, camera_data, &ui_scale);
             pointer_pos_by_camera
                 .entry(camera)
                 .or_default()
@@ -114,6 +195,13 @@ pub fn ui_picking(
 
     // The list of node entities hovered for each (camera, pointer) combo
     let mut hit_nodes = HashMap::<(Entity, PointerId), Vec<Entity>>::new();
+    // For touch pointers, the interactive node nearest to the touch within
+    // `TouchPickSettings::hit_radius_px`, and the distance to its center, used if no interactive
+    // node is directly under the touch.
+    let mut nearest_nodes = HashMap::<(Entity, PointerId), (f32, Entity)>::new();
+    // The (camera, pointer) combos with an interactive node directly under the pointer.
+    let mut interactive_hits = HashSet::<(Entity, PointerId)>::new();
+    let touch_radius = touch_settings.hit_radius_px / **ui_scale;
 
     // prepare an iterator that contains all the nodes that have the cursor in their rect,
     // from the top node to the bottom one. this will also reset the interaction to `None`
@@ -157,8 +245,19 @@ pub fn ui_picking(
             .map(|clip| node_rect.intersect(clip.clip))
             .unwrap_or(node_rect);
 
+        // Expand the hit area by the tolerance, which is in logical pixels, not scaled UI pixels
+        let visible_rect = match node.pick_tolerance {
+            Some(tolerance) => visible_rect.inflate(tolerance.screen_radius_px / **ui_scale),
+            None => visible_rect,
+        };
+
         let pointers_on_this_cam = pointer_pos_by_camera.get(&camera_entity);
 
+        // Containers, like the full-screen root node of a menu, are under almost every touch. Only
+        // nodes that are meant to be interacted with decide whether a touch missed its target.
+        let is_interactive =
+            node.interaction || node.pickable.is_some_and(|pickable| pickable.is_hoverable);
+
         // The mouse position relative to the node
         // (0., 0.) is the top-left corner, (1., 1.) is the bottom-right corner
         // Coordinates are relative to the entire node, not just the visible region.
@@ -173,12 +272,39 @@ pub fn ui_picking(
                     .entry((camera_entity, *pointer_id))
                     .or_default()
                     .push(*node_entity);
+                if is_interactive {
+                    interactive_hits.insert((camera_entity, *pointer_id));
+                }
+            } else if pointer_id.is_touch() && touch_radius > 0.0 && is_interactive {
+                let outside = (visible_rect.min - *cursor_position)
+                    .max(*cursor_position - visible_rect.max)
+                    .max(Vec2::ZERO);
+                if outside.length() > touch_radius {
+                    continue;
+                }
+                let center_distance = cursor_position.distance(visible_rect.center());
+                nearest_nodes
+                    .entry((camera_entity, *pointer_id))
+                    .and_modify(|nearest| {
+                        if center_distance < nearest.0 {
+                            *nearest = (center_distance, *node_entity);
+                        }
+                    })
+                    .or_insert((center_distance, *node_entity));
             }
         }
     }
 
+    for (key, (_, node_entity)) in nearest_nodes {
+        if interactive_hits.contains(&key) {
+            continue;
+        }
+        // The nearest node is drawn above the containers under the touch, which it usually sits in.
+        hit_nodes.entry(key).or_default().insert(0, node_entity);
+    }
+
     for ((camera, pointer), hovered_nodes) in hit_nodes.iter() {
-        // As soon as a node with a `Block` focus policy is detected, the iteration will stop on it
+        // As soon as a node that blocks lower nodes is detected, the iteration will stop on it
         // because it "captures" the interaction.
         let mut iter = node_query.iter_many_mut(hovered_nodes.iter());
         let mut picks = Vec::new();
@@ -193,7 +319,20 @@ pub fn ui_picking(
                 continue;
             };
 
-            picks.push((node.entity, HitData::new(camera_entity, depth, None, None)));
+            // The bounds of the node in the same window coordinates as pointer locations, the
+            // inverse of `ui_position`.
+            let viewport_min = camera_query
+                .get(camera_entity)
+                .ok()
+                .and_then(|(_, camera, _)| camera.logical_viewport_rect())
+                .map_or(Vec2::ZERO, |viewport| viewport.min);
+            let node_rect = node.node.logical_rect(node.global_transform);
+            let bounds = Rect {
+                min: node_rect.min * **ui_scale + viewport_min,
+                max: node_rect.max * **ui_scale + viewport_min,
+            };
+            let hit = HitData::new(camera_entity, depth, None, None).with_bounds(bounds);
+            picks.push((node.entity, hit));
 
             if let Some(pickable) = node.pickable {
                 // If an entity has a `Pickable` component, we will use that as the source of truth.
@@ -205,15 +344,305 @@ pub fn ui_picking(
                 break;
             }
 
-            depth += 0.00001; // keep depth near 0 for precision
+            // UI is drawn on the near plane, so keep depth near 0, only using it to preserve the
+            // order of the stack.
+            depth += settings.depth_step;
         }
 
-        let order = camera_query
-            .get(*camera)
-            .map(|(_, cam, _)| cam.order)
-            .unwrap_or_default() as f32
-            + 0.5; // bevy ui can run on any camera, it's a special case
+        let order = if settings.occlude_other_backends {
+            BevyUiBackendSettings::OCCLUDING_ORDER
+        } else {
+            camera_query
+                .get(*camera)
+                .map(|(_, cam, _)| cam.order)
+                .unwrap_or_default() as f32
+                + settings.order_offset // bevy ui can run on any camera, it's a special case
+        };
 
         output.send(PointerHits::new(*pointer, picks, order));
     }
 }
+
+/// Marks a [`Pickable`] inserted by [`sync_focus_policy`] to match the [`FocusPolicy`] of a node.
+/// It is updated when the focus policy changes, and removed when
+/// [`BevyUiBackendSettings::respect_focus_policy`] is disabled.
+#[derive(Debug, Clone, Copy, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct PickableFromFocusPolicy;
+
+/// Gives nodes without a [`Pickable`] component one matching their [`FocusPolicy`], while
+/// [`BevyUiBackendSettings::respect_focus_policy`] is enabled. This way, the focus policy is also
+/// respected when hits are combined with those of other backends.
+pub fn sync_focus_policy(
+    mut commands: Commands,
+    settings: Res<BevyUiBackendSettings>,
+    nodes: Query<
+        (Entity, Ref<FocusPolicy>, Has<PickableFromFocusPolicy>),
+        (
+            With<Node>,
+            Or<(Without<Pickable>, With<PickableFromFocusPolicy>)>,
+        ),
+    >,
+    synced: Query<Entity, With<PickableFromFocusPolicy>>,
+) {
+    if !settings.respect_focus_policy {
+        for entity in &synced {
+            commands
+                .entity(entity)
+                .remove::<(Pickable, PickableFromFocusPolicy)>();
+        }
+        return;
+    }
+    for (entity, focus_policy, is_synced) in &nodes {
+        if is_synced && !focus_policy.is_changed() {
+            continue;
+        }
+        let pickable = Pickable {
+            should_block_lower: *focus_policy == FocusPolicy::Block,
+            is_hoverable: true,
+        };
+        commands
+            .entity(entity)
+            .insert((pickable, PickableFromFocusPolicy));
+    }
+}
+
+/// Converts the position of a pointer [`Location`](bevy_picking_core::pointer::Location) into the
+/// coordinate space of bevy ui nodes drawn by `camera`: logical pixels, divided by the [`UiScale`],
+/// relative to the top left corner of the camera's viewport.
+pub fn ui_position(
+    location: &bevy_picking_core::pointer::Location,
+    camera: &Camera,
+    ui_scale: &UiScale,
+) -> Vec2 {
+    let mut position = location.position;
+    if let Some(viewport) = camera.logical_viewport_rect() {
+        position -= viewport.min;
+    }
+    position / **ui_scale
+}
+
+/// Returns the position of a pointer relative to the top left corner of a `node`, in the same
+/// units as the size of the node. Unlike [`RelativeCursorPosition`], this is not normalized, which
+/// is useful for widgets that need precise coordinates, like color pickers or sliders.
+///
+/// The `ui_position` can be computed from a pointer location with [`ui_position`].
+pub fn node_local_position(
+    node: &Node,
+    global_transform: &GlobalTransform,
+    ui_position: Vec2,
+) -> Vec2 {
+    ui_position - node.logical_rect(global_transform).min
+}
+
+/// Updates the [`DragBounds`] of UI nodes that are used to clamp drags with [`ClampDragToEntity`],
+/// so drags can be clamped to the rect of a node, like the track of a slider.
+pub fn update_drag_bounds(
+    mut commands: Commands,
+    clamps: Query<&ClampDragToEntity>,
+    camera_query: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
+    ui_scale: Res<UiScale>,
+    mut nodes: Query<(
+        &Node,
+        &GlobalTransform,
+        Option<&TargetCamera>,
+        Option<&mut DragBounds>,
+    )>,
+) {
+    for &ClampDragToEntity(entity) in &clamps {
+        let Ok((node, global_transform, target_camera, drag_bounds)) = nodes.get_mut(entity) else {
+            continue;
+        };
+        let Some(camera) = target_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera| camera_query.get(camera).ok())
+        else {
+            continue;
+        };
+        // Convert from ui coordinates back to the logical pixels of the render target, see
+        // `ui_position`.
+        let offset = camera
+            .logical_viewport_rect()
+            .map_or(Vec2::ZERO, |viewport| viewport.min);
+        let rect = node.logical_rect(global_transform);
+        let rect = Rect::from_corners(
+            rect.min * **ui_scale + offset,
+            rect.max * **ui_scale + offset,
+        );
+        match drag_bounds {
+            Some(mut drag_bounds) => {
+                drag_bounds.set_if_neq(DragBounds { rect });
+            }
+            None => {
+                commands.entity(entity).try_insert(DragBounds { rect });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetEvent, Assets};
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_math::UVec2;
+    use bevy_picking_core::{
+        focus::HoverMap, pointer::Location, CorePlugin, InteractionPlugin, PointerCoreBundle,
+    };
+    use bevy_reflect::Struct;
+    use bevy_render::{
+        camera::{camera_system, ManualTextureViews, RenderTarget, Viewport},
+        texture::Image,
+    };
+    use bevy_window::{Window, WindowCreated, WindowRef, WindowResized, WindowScaleFactorChanged};
+
+    use super::*;
+
+    /// An app with the bevy ui backend, a 100x100 primary window, and a mouse pointer at
+    /// `position` in it.
+    fn test_app(position: Vec2) -> App {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, BevyUiBackend))
+            .init_resource::<UiScale>()
+            .init_resource::<UiStack>()
+            .init_resource::<Assets<Image>>()
+            .init_resource::<ManualTextureViews>()
+            .add_event::<WindowCreated>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowScaleFactorChanged>()
+            .add_event::<AssetEvent<Image>>();
+        let mut window = Window::default();
+        window.resolution.set(100.0, 100.0);
+        let window = app.world_mut().spawn((window, PrimaryWindow)).id();
+        let target = RenderTarget::Window(WindowRef::Primary)
+            .normalize(Some(window))
+            .unwrap();
+        app.world_mut().spawn(
+            PointerCoreBundle::new(PointerId::Mouse).with_location(Location { target, position }),
+        );
+        app
+    }
+
+    /// Spawns a visible node drawn by `camera`, with its center at `center` in the camera's
+    /// viewport, on top of the other nodes.
+    fn spawn_node(app: &mut App, camera: Entity, center: Vec2, size: Vec2) -> Entity {
+        // The size of nodes is computed by the layout, which is not run here.
+        let mut node = Node::default();
+        node.field_mut("calculated_size").unwrap().apply(&size);
+        let mut visibility = ViewVisibility::default();
+        visibility.set();
+        let entity = app
+            .world_mut()
+            .spawn((
+                node,
+                GlobalTransform::from_translation(center.extend(0.0)),
+                visibility,
+                TargetCamera(camera),
+            ))
+            .id();
+        app.world_mut()
+            .resource_mut::<UiStack>()
+            .uinodes
+            .push(entity);
+        entity
+    }
+
+    /// Runs the app, and returns the nodes hit by the pointer, with the camera that hit them.
+    fn hits(app: &mut App) -> Vec<(Entity, Entity)> {
+        app.world_mut()
+            .run_system_once(camera_system::<OrthographicProjection>);
+        app.update();
+        app.world()
+            .resource::<Events<PointerHits>>()
+            .iter_current_update_events()
+            .flat_map(|hits| hits.picks.iter())
+            .map(|(entity, hit)| (*entity, hit.camera))
+            .collect()
+    }
+
+    #[test]
+    fn inactive_camera_does_not_pick() {
+        let mut app = test_app(Vec2::splat(50.0));
+        let camera = app
+            .world_mut()
+            .spawn((Camera::default(), OrthographicProjection::default()))
+            .id();
+        let node = spawn_node(&mut app, camera, Vec2::splat(50.0), Vec2::splat(10.0));
+        assert_eq!(hits(&mut app), vec![(node, camera)]);
+
+        app.world_mut().get_mut::<Camera>(camera).unwrap().is_active = false;
+        assert_eq!(hits(&mut app), vec![]);
+    }
+
+    #[test]
+    fn split_screen_does_not_pick_other_viewport() {
+        // The pointer is in the left half of the window, 5 pixels from the seam.
+        let mut app = test_app(Vec2::new(45.0, 50.0));
+        let mut spawn_camera = |x, order| {
+            let camera = Camera {
+                viewport: Some(Viewport {
+                    physical_position: UVec2::new(x, 0),
+                    physical_size: UVec2::new(50, 100),
+                    ..Default::default()
+                }),
+                order,
+                ..Default::default()
+            };
+            app.world_mut()
+                .spawn((camera, OrthographicProjection::default()))
+                .id()
+        };
+        let left = spawn_camera(0, 0);
+        let right = spawn_camera(50, 1);
+        // Both nodes sit on the seam, so they are under the pointer in viewport coordinates of
+        // either camera. Without the viewport check, the right camera would see the pointer 5
+        // pixels left of its viewport, inside its node.
+        let left_node = spawn_node(&mut app, left, Vec2::new(50.0, 50.0), Vec2::splat(20.0));
+        spawn_node(&mut app, right, Vec2::new(0.0, 50.0), Vec2::splat(20.0));
+
+        assert_eq!(hits(&mut app), vec![(left_node, left)]);
+    }
+
+    #[test]
+    fn focus_policy_pass_does_not_block() {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin, BevyUiBackend))
+            .init_resource::<bevy_time::Time>()
+            .init_resource::<UiScale>()
+            .init_resource::<UiStack>()
+            .insert_resource(BevyUiBackendSettings {
+                respect_focus_policy: true,
+                ..Default::default()
+            });
+        let location = Location {
+            target: RenderTarget::Window(WindowRef::Entity(Entity::PLACEHOLDER))
+                .normalize(None)
+                .unwrap(),
+            position: Vec2::ZERO,
+        };
+        app.world_mut()
+            .spawn(PointerCoreBundle::new(PointerId::Mouse).with_location(location));
+        let top = app
+            .world_mut()
+            .spawn((Node::default(), FocusPolicy::Pass))
+            .id();
+        let bottom = app
+            .world_mut()
+            .spawn((Node::default(), FocusPolicy::Block))
+            .id();
+
+        // The two nodes overlap under the pointer, as reported by `ui_picking`.
+        let hit = |depth| HitData::new(Entity::PLACEHOLDER, depth, None, None);
+        app.world_mut().send_event(PointerHits::new(
+            PointerId::Mouse,
+            vec![(top, hit(0.0)), (bottom, hit(0.1))],
+            0.5,
+        ));
+        app.update();
+
+        let hovered = &app.world().resource::<HoverMap>()[&PointerId::Mouse];
+        assert!(hovered.contains_key(&top));
+        assert!(hovered.contains_key(&bottom));
+    }
+}
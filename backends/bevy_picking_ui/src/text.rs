@@ -0,0 +1,88 @@
+//! Finds the section of a bevy ui [`Text`](bevy_text::Text) node under each pointer, to support
+//! inline links and hover effects on parts of a text without splitting it into separate nodes.
+//!
+//! Add a [`HoveredTextSection`] component to a text node, and it will be kept up to date with the
+//! index of the section under each pointer hovering that node. This requires the `text` feature.
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use bevy_render::prelude::*;
+use bevy_text::TextLayoutInfo;
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_utils::HashMap;
+
+use bevy_picking_core::{backend::prelude::*, focus::HoverMap};
+
+use crate::{node_local_position, ui_position};
+
+/// The index of the [`TextSection`](bevy_text::TextSection) under each pointer hovering this text
+/// node. Pointers that are over the node, but not over a glyph, are not included.
+///
+/// For example, a [`Pointer<Click>`](bevy_picking_core::events::Pointer) listener can look up the
+/// section under the clicked pointer with `sections.get(&event.pointer_id)` to resolve a link.
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct HoveredTextSection {
+    /// The section index under each pointer.
+    pub sections: HashMap<PointerId, usize>,
+}
+
+impl HoveredTextSection {
+    /// The index of the section under this pointer, if any.
+    pub fn get(&self, pointer_id: &PointerId) -> Option<usize> {
+        self.sections.get(pointer_id).copied()
+    }
+}
+
+/// Updates the [`HoveredTextSection`] of text nodes, using the glyphs in their [`TextLayoutInfo`].
+pub fn update_hovered_text_sections(
+    hover_map: Res<HoverMap>,
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    cameras: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
+    ui_scale: Res<UiScale>,
+    mut texts: Query<(
+        Entity,
+        &Node,
+        &GlobalTransform,
+        &TextLayoutInfo,
+        Option<&TargetCamera>,
+        &mut HoveredTextSection,
+    )>,
+) {
+    for (entity, node, transform, layout, target_camera, mut hovered) in &mut texts {
+        let Some(camera) = target_camera
+            .map(TargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera| cameras.get(camera).ok())
+        else {
+            continue;
+        };
+        // Glyphs are laid out in physical pixels, relative to the top left corner of the node.
+        let scale_factor = camera.target_scaling_factor().unwrap_or(1.0) * **ui_scale;
+
+        let sections = pointers
+            .iter()
+            .filter(|(pointer_id, _)| {
+                hover_map
+                    .get(*pointer_id)
+                    .is_some_and(|hovered| hovered.contains_key(&entity))
+            })
+            .filter_map(|(pointer_id, location)| {
+                let position = ui_position(location.location()?, camera, &ui_scale);
+                let local = node_local_position(node, transform, position) * scale_factor;
+                layout
+                    .glyphs
+                    .iter()
+                    .find(|glyph| {
+                        bevy_math::Rect::from_center_size(glyph.position, glyph.size)
+                            .contains(local)
+                    })
+                    .map(|glyph| (*pointer_id, glyph.section_index))
+            })
+            .collect();
+
+        hovered.set_if_neq(HoveredTextSection { sections });
+    }
+}
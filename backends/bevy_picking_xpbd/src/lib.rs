@@ -46,7 +46,7 @@ pub struct XpbdBackend;
 impl Plugin for XpbdBackend {
     fn build(&self, app: &mut App) {
         app.init_resource::<XpbdBackendSettings>()
-            .add_systems(PreUpdate, update_hits.in_set(PickSet::Backend))
+            .add_systems(PreUpdate, update_hits.in_set(BackendSet::Primary))
             .register_type::<XpbdBackendSettings>()
             .register_type::<XpbdPickable>();
     }
@@ -88,7 +88,7 @@ pub fn update_hits(
         let Ok((camera, cam_pickable, cam_layers)) = picking_cameras.get(ray_id.camera) else {
             continue;
         };
-        if backend_settings.require_markers && cam_pickable.is_none() || !camera.is_active {
+        if backend_settings.require_markers && cam_pickable.is_none() || !camera_picks(camera) {
             continue;
         }
 
@@ -0,0 +1,75 @@
+//! Measures the cost of sending pointer events while hovering a stack of entities.
+
+use std::time::Duration;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_picking_core::{
+    backend::{HitData, PointerHits},
+    pointer::{InputMove, InputPress, Location, PointerButton, PointerId},
+    CorePlugin, InteractionPlugin, Pickable, PointerCoreBundle,
+};
+use bevy_render::camera::RenderTarget;
+use bevy_time::Time;
+use bevy_window::WindowRef;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// The number of non-blocking entities stacked under the pointer.
+const STACK_SIZE: usize = 100;
+
+fn location(position: Vec2) -> Location {
+    Location {
+        target: RenderTarget::Window(WindowRef::Entity(Entity::PLACEHOLDER))
+            .normalize(None)
+            .unwrap(),
+        position,
+    }
+}
+
+fn hover_stack(c: &mut Criterion) {
+    let mut app = App::new();
+    app.add_plugins((CorePlugin, InteractionPlugin))
+        .init_resource::<Time>();
+    app.world_mut()
+        .spawn(PointerCoreBundle::new(PointerId::Mouse).with_location(location(Vec2::ZERO)));
+    let pickable = Pickable {
+        should_block_lower: false,
+        is_hoverable: true,
+    };
+    let picks: Vec<_> = (0..STACK_SIZE)
+        .map(|i| {
+            let entity = app.world_mut().spawn(pickable.clone()).id();
+            let hit = HitData::new(Entity::PLACEHOLDER, i as f32, None, None);
+            (entity, hit)
+        })
+        .collect();
+
+    // Every frame, the pointer moves and toggles its primary button, so each hovered entity
+    // receives a `Move`, and a `Down` or `Up` event.
+    let mut frame = 0;
+    c.bench_function("pointer_events_hover_stack_100", |b| {
+        b.iter(|| {
+            frame += 1;
+            let world = app.world_mut();
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(16));
+            world.send_event(PointerHits::new(PointerId::Mouse, picks.clone(), 0.0));
+            let position = Vec2::splat(frame as f32 % 100.0);
+            world.send_event(InputMove::new(
+                PointerId::Mouse,
+                location(position),
+                Vec2::ONE,
+            ));
+            world.send_event(match frame % 2 {
+                0 => InputPress::new_down(PointerId::Mouse, PointerButton::Primary),
+                _ => InputPress::new_up(PointerId::Mouse, PointerButton::Primary),
+            });
+            app.update();
+        });
+    });
+}
+
+criterion_group!(benches, hover_stack);
+criterion_main!(benches);
@@ -25,6 +25,10 @@
 //!   may want to early exit if it intersects an entity that blocks lower entities from being
 //!   picked.
 //!
+//! - Backends should add their systems to [`BackendSet::Primary`](crate::BackendSet), which is part
+//!   of [`PickSet::Backend`](crate::PickSet). Backends that need the hits of other backends can use
+//!   [`BackendSet::PostProcess`](crate::BackendSet) to run after them.
+//!
 //! ### Raycasting Backends
 //!
 //! Backends that require a ray to cast into the scene should use [`ray::RayMap`]. This
@@ -32,15 +36,17 @@
 //! viewports and DPI for you.
 
 use bevy_ecs::prelude::*;
-use bevy_math::Vec3;
+use bevy_math::{Rect, Vec3, Vec3A};
 use bevy_reflect::Reflect;
+use bevy_render::camera::Camera;
+use bevy_transform::components::GlobalTransform;
 
 /// Common imports for implementing a picking backend.
 pub mod prelude {
-    pub use super::{ray::RayMap, HitData, PointerHits};
+    pub use super::{camera_picks, ray::RayMap, HitData, PointerHits};
     pub use crate::{
-        pointer::{PointerId, PointerLocation},
-        PickSet, Pickable,
+        pointer::{any_active_pointer, PointerId, PointerLocation},
+        BackendSet, PickSet, PickTolerance, Pickable, TouchPickSettings,
     };
 }
 
@@ -57,7 +63,8 @@ pub struct PointerHits {
     /// An unordered collection of entities and their distance (depth) from the cursor.
     pub picks: Vec<(Entity, HitData)>,
     /// Set the order of this group of picks. Normally, this is the
-    /// [`bevy_render::camera::Camera::order`].
+    /// [`bevy_render::camera::Camera::order`]. Individual picks can override this with
+    /// [`HitData::layer`].
     ///
     /// Used to allow multiple `PointerHits` submitted for the same pointer to be ordered.
     /// `PointerHits` with a higher `order` will be checked before those with a lower `order`,
@@ -94,15 +101,35 @@ pub struct HitData {
     /// The camera entity used to detect this hit. Useful when you need to find the ray that was
     /// casted for this hit when using a raycasting backend.
     pub camera: Entity,
-    /// `depth` only needs to be self-consistent with other [`PointerHits`]s using the same
-    /// [`RenderTarget`](bevy_render::camera::RenderTarget). However, it is recommended to use the
-    /// distance from the pointer to the hit, measured from the near plane of the camera, to the
-    /// point, in world space.
+    /// The distance from the near plane of the camera to the hit, in world units. For raycasting
+    /// backends, this is the distance along the pointer's ray, starting from the near plane.
+    ///
+    /// Hits are sorted by `depth` within each [`PointerHits::order`], so all backends must use
+    /// these units to be correctly sorted against other backends using the same order. Backends
+    /// without a notion of depth, like UI, should report hits on or very near the near plane,
+    /// i.e. a depth close to `0.0`, increasing in the order their entities are drawn from top to
+    /// bottom.
     pub depth: f32,
     /// The position of the intersection in the world, if the data is available from the backend.
     pub position: Option<Vec3>,
     /// The normal vector of the hit test, if the data is available from the backend.
+    ///
+    /// Backends report this in world space: the raycast and physics backends report the world
+    /// space surface normal, and the sprite backend always reports `+Z`. Use
+    /// [`HitData::local_normal`] to get the normal in the space of the hit entity.
     pub normal: Option<Vec3>,
+    /// Overrides the [`PointerHits::order`] of the batch this hit was reported in. This allows a
+    /// backend to report hits on multiple layers in a single [`PointerHits`] event. If `None`, the
+    /// batch order is used.
+    pub layer: Option<f32>,
+    /// The index of the triangle that was hit, if the data is available from the backend. This can
+    /// be used with [`InstancePickable`](crate::focus::InstancePickable) to map a hit on a batched
+    /// or instanced mesh back to a logical entity.
+    pub triangle_index: Option<usize>,
+    /// The bounding rectangle of the hit entity on screen, in the same logical pixel coordinates as
+    /// the pointer [`Location`](crate::pointer::Location), if the data is available from the
+    /// backend. This is used to compute [`Over::entry_edge`](crate::events::Over::entry_edge).
+    pub bounds: Option<Rect>,
 }
 
 impl HitData {
@@ -113,8 +140,43 @@ impl HitData {
             depth,
             position,
             normal,
+            layer: None,
+            triangle_index: None,
+            bounds: None,
         }
     }
+
+    /// Overrides the order of the [`PointerHits`] this hit is reported in. See [`HitData::layer`].
+    pub fn with_layer(mut self, layer: f32) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets the [`HitData::triangle_index`] of this hit.
+    pub fn with_triangle_index(mut self, triangle_index: usize) -> Self {
+        self.triangle_index = Some(triangle_index);
+        self
+    }
+
+    /// Sets the [`HitData::bounds`] of this hit.
+    pub fn with_bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Returns the [`HitData::normal`] in the local space of the hit entity, given its
+    /// `transform`. This is useful for orienting decals or effects relative to the entity.
+    pub fn local_normal(&self, transform: &GlobalTransform) -> Option<Vec3> {
+        // Normals are transformed by the inverse transpose, so the transpose maps them back.
+        let normal = transform.affine().matrix3.transpose() * Vec3A::from(self.normal?);
+        Some(normal.normalize_or_zero().into())
+    }
+}
+
+/// Returns `true` if `camera` can produce hits. Inactive cameras draw nothing, so backends must not
+/// report hits through them, e.g. from a cutscene camera that was deactivated.
+pub fn camera_picks(camera: &Camera) -> bool {
+    camera.is_active
 }
 
 pub mod ray {
@@ -122,7 +184,7 @@ pub mod ray {
 
     use crate::backend::prelude::{PointerId, PointerLocation};
     use bevy_ecs::prelude::*;
-    use bevy_math::Ray3d;
+    use bevy_math::{DVec2, Dir3, Ray3d};
     use bevy_reflect::Reflect;
     use bevy_render::camera::Camera;
     use bevy_transform::prelude::GlobalTransform;
@@ -195,7 +257,7 @@ pub mod ray {
             ray_map.map.clear();
 
             for (camera_entity, camera, camera_tfm) in &cameras {
-                if !camera.is_active {
+                if !super::camera_picks(camera) {
                     continue;
                 }
 
@@ -227,6 +289,87 @@ pub mod ray {
             let viewport_logical = camera.to_logical(viewport.physical_position)?;
             viewport_pos -= viewport_logical;
         }
-        camera.viewport_to_world(camera_tfm, viewport_pos)
+        viewport_to_world(camera, camera_tfm, viewport_pos)
+    }
+
+    /// Returns a ray from the `camera` through `viewport_position`, in logical pixels relative to
+    /// the top left of the camera's viewport.
+    ///
+    /// This is equivalent to [`Camera::viewport_to_world`], but unprojects with `f64` math, which
+    /// reduces the error in the ray's direction when the camera is far from the origin, or has a
+    /// very large far plane. The resulting ray is still stored as `f32`, so the precision of its
+    /// origin is limited by the precision of the camera's [`GlobalTransform`], and hits far from
+    /// the origin are only as precise as the world positions of the entities being picked.
+    pub fn viewport_to_world(
+        camera: &Camera,
+        camera_tfm: &GlobalTransform,
+        viewport_position: bevy_math::Vec2,
+    ) -> Option<Ray3d> {
+        let target_size = camera.logical_viewport_size()?.as_dvec2();
+        let mut viewport_position = viewport_position.as_dvec2();
+        // Flip the Y co-ordinate origin from the top to the bottom.
+        viewport_position.y = target_size.y - viewport_position.y;
+        let ndc = viewport_position * 2. / target_size - DVec2::ONE;
+
+        let ndc_to_world =
+            camera_tfm.compute_matrix().as_dmat4() * camera.clip_from_view().as_dmat4().inverse();
+        let world_near_plane = ndc_to_world.project_point3(ndc.extend(1.));
+        // Using EPSILON because an ndc with Z = 0 returns NaNs.
+        let world_far_plane = ndc_to_world.project_point3(ndc.extend(f64::EPSILON));
+
+        let direction = (world_far_plane - world_near_plane).normalize_or_zero();
+        Dir3::new(direction.as_vec3()).ok().map(|direction| Ray3d {
+            origin: world_near_plane.as_vec3(),
+            direction,
+        })
+    }
+}
+
+pub mod window {
+    //! A fallback backend that reports the window under each pointer as a hit.
+    //!
+    //! The window is reported at the lowest possible order, so it is only hovered when no entity
+    //! above it blocks the pointer. Enable it with
+    //! [`PickingPluginsSettings::is_window_picking_enabled`](crate::PickingPluginsSettings).
+
+    use crate::backend::prelude::{HitData, PointerHits, PointerId, PointerLocation};
+    use bevy_ecs::prelude::*;
+    use bevy_render::camera::{Camera, NormalizedRenderTarget};
+    use bevy_window::PrimaryWindow;
+
+    /// Sends a [`PointerHits`] for the window entity under each pointer, using the active camera
+    /// with the lowest order that renders to that window.
+    pub fn window_picking(
+        pointers: Query<(&PointerId, &PointerLocation)>,
+        cameras: Query<(Entity, &Camera)>,
+        primary_window: Query<Entity, With<PrimaryWindow>>,
+        mut output: EventWriter<PointerHits>,
+    ) {
+        let primary_window = primary_window.get_single().ok();
+        for (pointer_id, pointer_location) in &pointers {
+            let Some(location) = pointer_location.location() else {
+                continue;
+            };
+            let NormalizedRenderTarget::Window(window_ref) = location.target else {
+                continue;
+            };
+            let Some(camera) = cameras
+                .iter()
+                .filter(|(_, camera)| crate::backend::camera_picks(camera))
+                .filter(|(_, camera)| {
+                    camera.target.normalize(primary_window).as_ref() == Some(&location.target)
+                })
+                .min_by_key(|(_, camera)| camera.order)
+                .map(|(entity, _)| entity)
+            else {
+                continue;
+            };
+            let hit = HitData::new(camera, f32::MAX, None, None);
+            output.send(PointerHits::new(
+                *pointer_id,
+                vec![(window_ref.entity(), hit)],
+                f32::NEG_INFINITY,
+            ));
+        }
     }
 }
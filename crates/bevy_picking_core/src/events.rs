@@ -1,21 +1,24 @@
 //! Processes data from input and backends, producing interaction events.
 
-use std::fmt::Debug;
+use std::{any::Any, fmt::Debug, sync::Arc, time::Duration};
 
 use crate::{
     backend::HitData,
     focus::{HoverMap, PreviousHoverMap},
     pointer::{
         self, InputMove, InputPress, Location, PointerButton, PointerId, PointerLocation,
-        PointerMap, PressDirection,
+        PointerMap, PointerPress, PressDirection, ProcessedMoves,
     },
 };
 use bevy_derive::{Deref, DerefMut};
-use bevy_ecs::prelude::*;
+use bevy_ecs::{entity::Entities, prelude::*, system::SystemParam};
 use bevy_eventlistener::prelude::*;
-use bevy_math::Vec2;
+use bevy_math::{Rect, Vec2, Vec3, Vec4};
 use bevy_reflect::prelude::*;
-use bevy_utils::{tracing::debug, HashMap};
+use bevy_render::{camera::Camera, primitives::Aabb};
+use bevy_time::Time;
+use bevy_transform::components::GlobalTransform;
+use bevy_utils::{tracing::debug, HashMap, HashSet};
 
 /// Stores the common data needed for all `PointerEvent`s.
 #[derive(Clone, PartialEq, Debug, Reflect, Event, EntityEvent)]
@@ -62,6 +65,75 @@ impl<E: Debug + Clone + Reflect> Pointer<E> {
     }
 }
 
+/// Extends [`On`] with a listener that notifies an entity of the caller's choosing, instead of the
+/// target, or a global [`EventReader`]. This is useful when a click should be handled by some
+/// controller entity.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_eventlistener::prelude::*;
+/// # use bevy_picking_core::events::{Click, Pointer, SendToEntity};
+/// #[derive(Component, Clone)]
+/// struct Pressed;
+///
+/// let listener = On::<Pointer<Click>>::send_to_entity(|event| event.listener(), Pressed);
+/// ```
+pub trait SendToEntity<E: EntityEvent> {
+    /// Inserts `bundle` on the entity returned by `select` any time this event listener is
+    /// triggered. Nothing is inserted if that entity no longer exists.
+    fn send_to_entity(
+        select: impl 'static + Send + Sync + Fn(&ListenerInput<E>) -> Entity,
+        bundle: impl Bundle + Clone,
+    ) -> Self;
+}
+
+impl<E: EntityEvent> SendToEntity<E> for On<E> {
+    fn send_to_entity(
+        select: impl 'static + Send + Sync + Fn(&ListenerInput<E>) -> Entity,
+        bundle: impl Bundle + Clone,
+    ) -> Self {
+        On::<E>::commands_mut(move |event, commands| {
+            if let Some(mut entity) = commands.get_entity(select(event)) {
+                entity.try_insert(bundle.clone());
+            }
+        })
+    }
+}
+
+/// Extends [`On`] with a listener that records the event into a component on the listener, so
+/// systems can read what happened later. Unlike [`On::listener_insert`], the inserted component is
+/// built from the event.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_eventlistener::prelude::*;
+/// # use bevy_math::Vec2;
+/// # use bevy_picking_core::events::{Click, Pointer, RecordTo};
+/// #[derive(Component)]
+/// struct LastClick(Vec2);
+///
+/// impl From<&ListenerInput<Pointer<Click>>> for LastClick {
+///     fn from(event: &ListenerInput<Pointer<Click>>) -> Self {
+///         LastClick(event.pointer_location.position)
+///     }
+/// }
+///
+/// let listener = On::<Pointer<Click>>::record_to::<LastClick>();
+/// ```
+pub trait RecordTo<E: EntityEvent> {
+    /// Inserts the component `C`, built from the event, on the listener any time this event
+    /// listener is triggered, replacing the previous value.
+    fn record_to<C: Component + for<'a> From<&'a ListenerInput<E>>>() -> Self;
+}
+
+impl<E: EntityEvent> RecordTo<E> for On<E> {
+    fn record_to<C: Component + for<'a> From<&'a ListenerInput<E>>>() -> Self {
+        On::<E>::listener_commands_mut(|event, commands| {
+            commands.try_insert(C::from(&*event));
+        })
+    }
+}
+
 /// Fires when a pointer is no longer available.
 #[derive(Event, Clone, PartialEq, Debug, Reflect)]
 pub struct PointerCancel {
@@ -75,9 +147,50 @@ pub struct PointerCancel {
 pub struct Over {
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// The edge of the `target` entity the pointer crossed to enter it, found from where the
+    /// pointer was last frame relative to the [`HitData::bounds`] of the entity. If the pointer did
+    /// not move, for example when the entity moved under a stationary pointer, this is the edge
+    /// nearest to the pointer. This is `None` if the backend does not report bounds.
+    pub entry_edge: Option<Edge>,
+}
+
+/// An edge of an entity, as seen on screen. See [`Over::entry_edge`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+pub enum Edge {
+    /// The left edge.
+    Left,
+    /// The right edge.
+    Right,
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    Bottom,
+}
+
+impl Edge {
+    /// Returns the edge of `bounds` facing `position`, in screen space: the edge `position` is
+    /// furthest beyond if it is outside of `bounds`, or the nearest edge if it is inside.
+    pub fn facing(bounds: Rect, position: Vec2) -> Self {
+        // How far the position is beyond each edge, negative if it is on the inner side. Screen
+        // space has +y pointing down, so the top edge is at `bounds.min.y`.
+        let mut facing = (Edge::Left, bounds.min.x - position.x);
+        for (edge, distance) in [
+            (Edge::Right, position.x - bounds.max.x),
+            (Edge::Top, bounds.min.y - position.y),
+            (Edge::Bottom, position.y - bounds.max.y),
+        ] {
+            if distance > facing.1 {
+                facing = (edge, distance);
+            }
+        }
+        facing.0
+    }
 }
 
 /// Fires when a the pointer crosses out of the bounds of the `target` entity.
+///
+/// This is also sent when a hovered entity is despawned. Because the `target` no longer exists by
+/// then, the event is not bubbled up to the entity's former ancestors.
 #[derive(Clone, PartialEq, Debug, Reflect)]
 pub struct Out {
     /// Information about the latest prior picking intersection.
@@ -89,6 +202,10 @@ pub struct Out {
 pub struct Down {
     /// Pointer button pressed to trigger this event.
     pub button: PointerButton,
+    /// The buttons of the pointer held down when this event was sent, including `button`. Use this
+    /// to detect button chords, like the secondary button being pressed while the primary button
+    /// is held.
+    pub pressed_buttons: PointerPress,
     /// Information about the picking intersection.
     pub hit: HitData,
 }
@@ -98,6 +215,9 @@ pub struct Down {
 pub struct Up {
     /// Pointer button lifted to trigger this event.
     pub button: PointerButton,
+    /// The buttons of the pointer still held down when this event was sent, not including
+    /// `button`.
+    pub pressed_buttons: PointerPress,
     /// Information about the picking intersection.
     pub hit: HitData,
 }
@@ -112,6 +232,34 @@ pub struct Click {
     pub hit: HitData,
 }
 
+/// Fires when the `target` entity is clicked with the [`ContextMenuSettings::button`], the
+/// secondary button by default. This is sent after the [`Click`], and is useful for opening context
+/// menus at the [`Pointer::pointer_location`].
+#[derive(Clone, PartialEq, Debug, Reflect)]
+pub struct ContextMenu {
+    /// Information about the picking intersection.
+    pub hit: HitData,
+}
+
+/// Controls which clicks send [`ContextMenu`] events.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct ContextMenuSettings {
+    /// Should [`ContextMenu`] events be sent?
+    pub is_enabled: bool,
+    /// The pointer button that opens context menus when clicked.
+    pub button: PointerButton,
+}
+
+impl Default for ContextMenuSettings {
+    fn default() -> Self {
+        Self {
+            is_enabled: true,
+            button: PointerButton::Secondary,
+        }
+    }
+}
+
 /// Fires while a pointer is moving over the `target` entity.
 #[derive(Clone, PartialEq, Debug, Reflect)]
 pub struct Move {
@@ -119,6 +267,9 @@ pub struct Move {
     pub hit: HitData,
     /// The change in position since the last move event.
     pub delta: Vec2,
+    /// The time elapsed since the last move event of this pointer. If the pointer moved multiple
+    /// times in a single frame, the elapsed time is split evenly between those events.
+    pub delta_time: Duration,
 }
 
 /// Fires when the `target` entity receives a pointer down event followed by a pointer move event.
@@ -139,6 +290,159 @@ pub struct Drag {
     pub distance: Vec2,
     /// The change in position since the last drag event.
     pub delta: Vec2,
+    /// The time elapsed since the last drag event of this pointer on the `target` entity. If the
+    /// pointer moved multiple times in a single frame, the elapsed time is split evenly between
+    /// those events.
+    pub delta_time: Duration,
+}
+
+/// Constrains the [`Drag::distance`] and [`Drag::delta`] reported for the entity this component is
+/// on, e.g. to build sliders or snap to a grid. The pointer location in the event is not modified.
+///
+/// The axis constraint is applied first, followed by the grid constraint.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct DragConstraint {
+    /// If set, the drag distance is projected onto this axis.
+    pub axis: Option<Vec2>,
+    /// If set, the drag distance is rounded to the nearest multiple of this value on each axis.
+    pub grid: Option<f32>,
+}
+
+impl DragConstraint {
+    /// Only allow dragging along the x axis.
+    pub const HORIZONTAL: Self = Self {
+        axis: Some(Vec2::X),
+        grid: None,
+    };
+
+    /// Only allow dragging along the y axis.
+    pub const VERTICAL: Self = Self {
+        axis: Some(Vec2::Y),
+        grid: None,
+    };
+
+    /// Snap the drag distance to increments of `size`.
+    pub fn grid(size: f32) -> Self {
+        Self {
+            axis: None,
+            grid: Some(size),
+        }
+    }
+
+    /// Sets the [`grid`](Self::grid) increment of this constraint.
+    pub fn with_grid(mut self, size: f32) -> Self {
+        self.grid = Some(size);
+        self
+    }
+
+    /// Apply this constraint to a drag distance.
+    pub fn constrain(&self, distance: Vec2) -> Vec2 {
+        let mut distance = distance;
+        if let Some(axis) = self.axis.and_then(|axis| axis.try_normalize()) {
+            distance = distance.project_onto_normalized(axis);
+        }
+        if let Some(grid) = self.grid.filter(|grid| *grid > 0.0) {
+            distance = (distance / grid).round() * grid;
+        }
+        distance
+    }
+}
+
+/// Clamps the [`Drag::distance`] and [`Drag::delta`] reported for the entity this component is on,
+/// so the point being dragged stays within the bounds of another entity. For example, a slider
+/// knob can be clamped to its track. The pointer location in the event is not modified.
+///
+/// The bounds of the entity are its [`DragBounds`] if it has them, which the `bevy_ui` backend adds
+/// to UI nodes used as bounds. Otherwise, the [`Aabb`] of the entity is projected onto the viewport
+/// of the camera the drag started in, which works for sprites and 2d meshes. Bounds are not
+/// available for entities seen through a perspective camera, in which case this does nothing.
+///
+/// Clamping is applied after the [`DragConstraint`] of the entity, if any.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct ClampDragToEntity(pub Entity);
+
+/// The bounds used to clamp drags with [`ClampDragToEntity`], in logical pixels of the render
+/// target, the same space as pointer positions. Add this to an entity to override its bounds.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct DragBounds {
+    /// The region the drag point is clamped to.
+    pub rect: Rect,
+}
+
+/// A system parameter that applies the [`DragConstraint`] and [`ClampDragToEntity`] of dragged
+/// entities to drag distances.
+#[derive(SystemParam)]
+pub struct DragLimits<'w, 's> {
+    constraints: Query<'w, 's, &'static DragConstraint>,
+    clamps: Query<'w, 's, &'static ClampDragToEntity>,
+    bounds: Query<
+        'w,
+        's,
+        (
+            Option<&'static DragBounds>,
+            Option<&'static Aabb>,
+            Option<&'static GlobalTransform>,
+        ),
+    >,
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform)>,
+}
+
+impl DragLimits<'_, '_> {
+    /// Returns the drag `distance` of the `dragged` entity, after applying its constraints.
+    pub fn apply(&self, dragged: Entity, drag: &DragEntry, distance: Vec2) -> Vec2 {
+        let mut distance = match self.constraints.get(dragged) {
+            Ok(constraint) => constraint.constrain(distance),
+            Err(_) => distance,
+        };
+        if let Ok(ClampDragToEntity(bounding)) = self.clamps.get(dragged) {
+            match self.bounds(*bounding, drag.camera) {
+                Some(rect) => {
+                    let point = (drag.start_pos + distance).clamp(rect.min, rect.max);
+                    distance = point - drag.start_pos;
+                }
+                None => {
+                    debug!("Unable to clamp the drag of {dragged:?}, {bounding:?} has no bounds")
+                }
+            }
+        }
+        distance
+    }
+
+    /// The bounds of `entity` in the render target of `camera`, see [`ClampDragToEntity`].
+    fn bounds(&self, entity: Entity, camera: Entity) -> Option<Rect> {
+        let (drag_bounds, aabb, transform) = self.bounds.get(entity).ok()?;
+        if let Some(drag_bounds) = drag_bounds {
+            return Some(drag_bounds.rect);
+        }
+        let (aabb, transform) = aabb.zip(transform)?;
+        let (camera, camera_transform) = self.cameras.get(camera).ok()?;
+        // A projected box only matches the outline of the entity with an orthographic projection.
+        if camera.clip_from_view().row(3) != Vec4::W {
+            return None;
+        }
+        let offset = camera.logical_viewport_rect()?.min;
+        let center = Vec3::from(aabb.center);
+        let half_extents = Vec3::from(aabb.half_extents);
+        [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)]
+            .into_iter()
+            .map(|(x, y)| {
+                let corner =
+                    transform.transform_point(center + half_extents * Vec3::new(x, y, 0.0));
+                Some(camera.world_to_viewport(camera_transform, corner)? + offset)
+            })
+            .try_fold(None, |rect: Option<Rect>, corner| {
+                let corner = corner?;
+                Some(Some(
+                    rect.map_or(Rect::from_corners(corner, corner), |rect| {
+                        rect.union_point(corner)
+                    }),
+                ))
+            })
+            .flatten()
+    }
 }
 
 /// Fires when a pointer is dragging the `target` entity and a pointer up event is received.
@@ -159,6 +463,16 @@ pub struct DragEnter {
     pub dragged: Entity,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// The [`DragPayload`] of the `dragged` entity, if it has one.
+    #[reflect(ignore)]
+    pub payload: Option<DragPayload>,
+}
+
+impl DragEnter {
+    /// The payload of the `dragged` entity, if it has a [`DragPayload`] of type `T`.
+    pub fn payload<T: Any>(&self) -> Option<&T> {
+        self.payload.as_ref().and_then(DragPayload::get)
+    }
 }
 
 /// Fires while the `dragged` entity is being dragged over the `target` entity.
@@ -170,6 +484,16 @@ pub struct DragOver {
     pub dragged: Entity,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// The [`DragPayload`] of the `dragged` entity, if it has one.
+    #[reflect(ignore)]
+    pub payload: Option<DragPayload>,
+}
+
+impl DragOver {
+    /// The payload of the `dragged` entity, if it has a [`DragPayload`] of type `T`.
+    pub fn payload<T: Any>(&self) -> Option<&T> {
+        self.payload.as_ref().and_then(DragPayload::get)
+    }
 }
 
 /// Fires when a pointer dragging the `dragged` entity leaves the `target` entity.
@@ -192,17 +516,143 @@ pub struct Drop {
     pub dropped: Entity,
     /// Information about the picking intersection.
     pub hit: HitData,
+    /// The [`DragPayload`] of the `dropped` entity, if it has one.
+    #[reflect(ignore)]
+    pub payload: Option<DragPayload>,
+}
+
+impl Drop {
+    /// The payload of the `dropped` entity, if it has a [`DragPayload`] of type `T`.
+    pub fn payload<T: Any>(&self) -> Option<&T> {
+        self.payload.as_ref().and_then(DragPayload::get)
+    }
+}
+
+/// Data carried by a dragged entity, that is passed to the entities it is dragged over in the
+/// [`DragEnter`], [`DragOver`], and [`Drop`] events. This makes it possible for a drop target to
+/// inspect what is being dropped, like an inventory slot accepting an item stack, without querying
+/// the dragged entity.
+///
+/// The payload can be any type, and is read back with [`DragPayload::get`], or with the `payload`
+/// method of the events. Cloning a payload is cheap, because the data is shared.
+#[derive(Component, Clone)]
+pub struct DragPayload(Arc<dyn Any + Send + Sync>);
+
+impl DragPayload {
+    /// Create a payload holding this `value`.
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+
+    /// Returns the payload if it is of type `T`.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl Debug for DragPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DragPayload").finish_non_exhaustive()
+    }
+}
+
+/// Payloads are equal if they share the same data.
+impl PartialEq for DragPayload {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Fires repeatedly while a pointer button is held down over the `target` entity, like keyboard key
+/// repeat. The first event fires after [`HoldRepeatSettings::initial_delay`], then every
+/// [`HoldRepeatSettings::interval`], until the button is released or the pointer leaves the entity.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+pub struct HoldRepeat {
+    /// Pointer button held down to trigger this event.
+    pub button: PointerButton,
+    /// The number of times this event has fired during this hold, starting at `1`.
+    pub count: u32,
+    /// Information about the picking intersection.
+    pub hit: HitData,
+}
+
+/// Controls the timing of [`HoldRepeat`] events.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct HoldRepeatSettings {
+    /// How long a button needs to be held down before the first [`HoldRepeat`] event is sent.
+    pub initial_delay: Duration,
+    /// The time between subsequent [`HoldRepeat`] events.
+    pub interval: Duration,
+}
+
+impl Default for HoldRepeatSettings {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            interval: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Controls when a [`Click`] is sent, see [`send_click_and_drag_events`].
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct ClickSettings {
+    /// How far, in logical pixels, the pointer can move between a button being pressed and
+    /// released while still sending a [`Click`]. Moving further than this is treated as a drag.
+    /// This keeps taps from being swallowed by small finger movements on touch screens. Defaults to
+    /// `4.0`.
+    pub drag_tolerance: f32,
+}
+
+impl Default for ClickSettings {
+    fn default() -> Self {
+        Self {
+            drag_tolerance: 4.0,
+        }
+    }
+}
+
+/// Spreads the time elapsed since `latest` evenly over the `remaining` events sent this frame, and
+/// advances `latest` by the returned duration.
+fn split_delta_time(latest: &mut Duration, now: Duration, remaining: u32) -> Duration {
+    let delta_time = now.saturating_sub(*latest) / remaining.max(1);
+    *latest += delta_time;
+    delta_time
+}
+
+/// Returns the moves processed since the calling system last ran, so the same moves are not used
+/// twice while input processing is disabled.
+fn new_moves(moves: &Res<ProcessedMoves>) -> Vec<InputMove> {
+    match moves.is_changed() {
+        true => moves.to_vec(),
+        false => Vec::new(),
+    }
+}
+
+/// Counts the [`InputMove`]s of each pointer, used to split delta time between them.
+fn count_moves(moves: &[InputMove]) -> HashMap<PointerId, u32> {
+    let mut counts = HashMap::new();
+    for input_move in moves {
+        *counts.entry(input_move.pointer_id).or_default() += 1;
+    }
+    counts
 }
 
 /// Generates pointer events from input and focus data
 pub fn pointer_events(
     // Input
+    time: Res<Time>,
     mut input_presses: EventReader<InputPress>,
-    mut input_moves: EventReader<pointer::InputMove>,
+    input_moves: Res<ProcessedMoves>,
     pointer_map: Res<PointerMap>,
     pointers: Query<&PointerLocation>,
     hover_map: Res<HoverMap>,
     previous_hover_map: Res<PreviousHoverMap>,
+    // Local
+    mut latest_move_time: Local<HashMap<PointerId, Duration>>,
+    mut held_buttons: Local<HashMap<PointerId, PointerPress>>,
     // Output
     mut pointer_move: EventWriter<Pointer<Move>>,
     mut pointer_over: EventWriter<Pointer<Over>>,
@@ -217,128 +667,172 @@ pub fn pointer_events(
             .and_then(|pointer| pointer.location.clone())
     };
 
+    let now = time.elapsed();
+    latest_move_time.retain(|pointer_id, _| pointer_map.get_entity(*pointer_id).is_some());
+    held_buttons.retain(|pointer_id, _| pointer_map.get_entity(*pointer_id).is_some());
+    let input_moves = new_moves(&input_moves);
+    let mut remaining_moves = count_moves(&input_moves);
+    let mut frame_delta = HashMap::<PointerId, Vec2>::new();
+    for input_move in &input_moves {
+        *frame_delta.entry(input_move.pointer_id).or_default() += input_move.delta;
+    }
+
     for InputMove {
         pointer_id,
         location,
         delta,
-    } in input_moves.read().cloned()
+    } in input_moves
     {
-        for (hovered_entity, hit) in hover_map
-            .get(&pointer_id)
-            .iter()
-            .flat_map(|h| h.iter().map(|(entity, data)| (*entity, data.to_owned())))
-        {
-            pointer_move.send(Pointer::new(
-                pointer_id,
-                location.clone(),
-                hovered_entity,
-                Move { hit, delta },
-            ));
-        }
+        let remaining = remaining_moves.entry(pointer_id).or_default();
+        let latest = latest_move_time
+            .entry(pointer_id)
+            .or_insert_with(|| now.saturating_sub(time.delta()));
+        let delta_time = split_delta_time(latest, now, *remaining);
+        *remaining = remaining.saturating_sub(1);
+
+        pointer_move.send_batch(
+            hover_map
+                .get(&pointer_id)
+                .iter()
+                .flat_map(|h| h.iter())
+                .map(|(hovered_entity, hit)| {
+                    Pointer::new(
+                        pointer_id,
+                        location.clone(),
+                        *hovered_entity,
+                        Move {
+                            hit: hit.clone(),
+                            delta,
+                            delta_time,
+                        },
+                    )
+                }),
+        );
     }
 
     for press_event in input_presses.read() {
+        let pointer_id = press_event.pointer_id;
         let button = press_event.button;
-        // We use the previous hover map because we want to consider pointers that just left the
-        // entity. Without this, touch inputs would never send up events because they are lifted up
-        // and leave the bounds of the entity at the same time.
-        for (hovered_entity, hit) in previous_hover_map
-            .get(&press_event.pointer_id)
-            .iter()
-            .flat_map(|h| h.iter().map(|(entity, data)| (*entity, data.clone())))
-        {
-            if let PressDirection::Up = press_event.direction {
-                let Some(location) = pointer_location(press_event.pointer_id) else {
-                    debug!(
-                        "Unable to get location for pointer {:?} during event {:?}",
-                        press_event.pointer_id, press_event
-                    );
-                    continue;
-                };
-                pointer_up.send(Pointer::new(
-                    press_event.pointer_id,
-                    location,
-                    hovered_entity,
-                    Up { button, hit },
-                ));
+        // Replay the presses in order, so each event sees the buttons held at the time it was
+        // sent, even if several presses of the pointer arrived this frame.
+        let pressed = held_buttons.entry(pointer_id).or_default();
+        pressed.apply(press_event);
+        let pressed_buttons = *pressed;
+        let Some(location) = pointer_location(pointer_id) else {
+            debug!(
+                "Unable to get location for pointer {:?} during event {:?}",
+                pointer_id, press_event
+            );
+            continue;
+        };
+        match press_event.direction {
+            // We use the previous hover map because we want to consider pointers that just left
+            // the entity. Without this, touch inputs would never send up events because they are
+            // lifted up and leave the bounds of the entity at the same time.
+            PressDirection::Up => {
+                pointer_up.send_batch(
+                    previous_hover_map
+                        .get(&pointer_id)
+                        .iter()
+                        .flat_map(|h| h.iter())
+                        .map(|(hovered_entity, hit)| {
+                            Pointer::new(
+                                pointer_id,
+                                location.clone(),
+                                *hovered_entity,
+                                Up {
+                                    button,
+                                    pressed_buttons,
+                                    hit: hit.clone(),
+                                },
+                            )
+                        }),
+                );
             }
-        }
-        for (hovered_entity, hit) in hover_map
-            .get(&press_event.pointer_id)
-            .iter()
-            .flat_map(|h| h.iter().map(|(entity, data)| (*entity, data.clone())))
-        {
-            if let PressDirection::Down = press_event.direction {
-                let Some(location) = pointer_location(press_event.pointer_id) else {
-                    debug!(
-                        "Unable to get location for pointer {:?} during event {:?}",
-                        press_event.pointer_id, press_event
-                    );
-                    continue;
-                };
-                pointer_down.send(Pointer::new(
-                    press_event.pointer_id,
-                    location,
-                    hovered_entity,
-                    Down { button, hit },
-                ));
+            PressDirection::Down => {
+                pointer_down.send_batch(
+                    hover_map
+                        .get(&pointer_id)
+                        .iter()
+                        .flat_map(|h| h.iter())
+                        .map(|(hovered_entity, hit)| {
+                            Pointer::new(
+                                pointer_id,
+                                location.clone(),
+                                *hovered_entity,
+                                Down {
+                                    button,
+                                    pressed_buttons,
+                                    hit: hit.clone(),
+                                },
+                            )
+                        }),
+                );
             }
         }
     }
 
     // If the entity is hovered...
-    for (pointer_id, hovered_entity, hit) in hover_map
+    let over_events = hover_map
         .iter()
         .flat_map(|(id, hashmap)| hashmap.iter().map(|data| (*id, *data.0, data.1.clone())))
-    {
         // ...but was not hovered last frame...
-        if !previous_hover_map
-            .get(&pointer_id)
-            .iter()
-            .any(|e| e.contains_key(&hovered_entity))
-        {
+        .filter(|(pointer_id, hovered_entity, _)| {
+            !previous_hover_map
+                .get(pointer_id)
+                .iter()
+                .any(|e| e.contains_key(hovered_entity))
+        })
+        .filter_map(|(pointer_id, hovered_entity, hit)| {
             let Some(location) = pointer_location(pointer_id) else {
                 debug!(
                     "Unable to get location for pointer {:?} during pointer over",
                     pointer_id
                 );
-                continue;
+                return None;
             };
-            pointer_over.send(Pointer::new(
+            // Where the pointer was before moving this frame.
+            let previous_position =
+                location.position - frame_delta.get(&pointer_id).copied().unwrap_or_default();
+            let entry_edge = hit
+                .bounds
+                .map(|bounds| Edge::facing(bounds, previous_position));
+            Some(Pointer::new(
                 pointer_id,
                 location,
                 hovered_entity,
-                Over { hit },
-            ));
-        }
-    }
+                Over { hit, entry_edge },
+            ))
+        });
+    pointer_over.send_batch(over_events);
 
     // If the entity was hovered by a specific pointer last frame...
-    for (pointer_id, hovered_entity, hit) in previous_hover_map
+    let out_events = previous_hover_map
         .iter()
         .flat_map(|(id, hashmap)| hashmap.iter().map(|data| (*id, *data.0, data.1.clone())))
-    {
         // ...but is now not being hovered by that same pointer...
-        if !hover_map
-            .get(&pointer_id)
-            .iter()
-            .any(|e| e.contains_key(&hovered_entity))
-        {
+        .filter(|(pointer_id, hovered_entity, _)| {
+            !hover_map
+                .get(pointer_id)
+                .iter()
+                .any(|e| e.contains_key(hovered_entity))
+        })
+        .filter_map(|(pointer_id, hovered_entity, hit)| {
             let Some(location) = pointer_location(pointer_id) else {
                 debug!(
                     "Unable to get location for pointer {:?} during pointer out",
                     pointer_id
                 );
-                continue;
+                return None;
             };
-            pointer_out.send(Pointer::new(
+            Some(Pointer::new(
                 pointer_id,
                 location,
                 hovered_entity,
                 Out { hit },
-            ));
-        }
-    }
+            ))
+        });
+    pointer_out.send_batch(out_events);
 }
 
 /// Maps pointers to the entities they are dragging.
@@ -352,25 +846,105 @@ pub struct DragEntry {
     pub start_pos: Vec2,
     /// The latest position of the pointer during this drag, used to compute deltas.
     pub latest_pos: Vec2,
+    /// The time of the latest drag event, as measured by [`Time::elapsed`], used to compute delta
+    /// times.
+    pub latest_time: Duration,
+    /// The camera that the dragged entity was pressed in.
+    pub camera: Entity,
+}
+
+/// The progress of a press-drag-release gesture for a single pointer button, as tracked by
+/// [`send_click_and_drag_events`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum GestureState {
+    /// The button is not pressed down over an entity.
+    #[default]
+    Idle,
+    /// The button was pressed down over an entity, and the pointer has not moved since. If the
+    /// button is released in this state, a [`Click`] is sent.
+    Pressed,
+    /// The pointer has moved while the button was held down. Releasing the button in this state
+    /// ends the drag, and does not send a [`Click`].
+    Dragging,
+}
+
+/// The current [`GestureState`] of every pointer button. Buttons that are not in the map are
+/// [`GestureState::Idle`].
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct GestureMap(pub HashMap<(PointerId, PointerButton), GestureState>);
+
+impl GestureMap {
+    /// Get the [`GestureState`] of this pointer's button.
+    pub fn get_state(&self, pointer_id: PointerId, button: PointerButton) -> GestureState {
+        self.get(&(pointer_id, button)).copied().unwrap_or_default()
+    }
+
+    /// Sets the state of this pointer's button, returning the transition if the state changed.
+    fn transition(
+        &mut self,
+        pointer_id: PointerId,
+        button: PointerButton,
+        to: GestureState,
+    ) -> Option<GestureTransition> {
+        let from = self.insert((pointer_id, button), to).unwrap_or_default();
+        (from != to).then_some(GestureTransition {
+            pointer_id,
+            button,
+            from,
+            to,
+        })
+    }
+}
+
+/// Fires when the [`GestureState`] of a pointer button changes.
+#[derive(Event, Clone, PartialEq, Debug, Reflect)]
+pub struct GestureTransition {
+    /// ID of the pointer whose gesture changed.
+    #[reflect(ignore)]
+    pub pointer_id: PointerId,
+    /// The pointer button whose gesture changed.
+    pub button: PointerButton,
+    /// The state before this transition.
+    pub from: GestureState,
+    /// The state after this transition.
+    pub to: GestureState,
 }
 
 /// Uses pointer events to determine when click and drag events occur.
+///
+/// A [`Click`] is not sent if the pointer was dragged between the button being pressed and
+/// released, to avoid triggering click handlers at the end of a drag. The pointer is only
+/// considered dragged, and its [`GestureState`] only becomes [`GestureState::Dragging`], once it
+/// has moved further than [`ClickSettings::drag_tolerance`] from where the button was pressed.
+/// [`Drag`] events are sent for any movement. The [`GestureState`] of each pointer button is kept
+/// up to date in the [`GestureMap`].
+///
+/// Entities that are despawned while pressed or dragged are forgotten, so no further drag events
+/// are sent to them.
 pub fn send_click_and_drag_events(
     // Input
+    time: Res<Time>,
+    entities: &Entities,
     mut pointer_down: EventReader<Pointer<Down>>,
     mut pointer_up: EventReader<Pointer<Up>>,
-    mut input_move: EventReader<InputMove>,
+    input_move: Res<ProcessedMoves>,
     mut input_presses: EventReader<InputPress>,
     pointer_map: Res<PointerMap>,
     pointers: Query<&PointerLocation>,
+    (drag_limits, click_settings): (DragLimits, Res<ClickSettings>),
     // Locals
     mut down_map: Local<HashMap<(PointerId, PointerButton), HashMap<Entity, Pointer<Down>>>>,
+    mut dragged_since_down: Local<HashSet<(PointerId, PointerButton)>>,
     // Output
     mut drag_map: ResMut<DragMap>,
+    mut gesture_map: ResMut<GestureMap>,
+    mut gesture_transitions: EventWriter<GestureTransition>,
     mut pointer_click: EventWriter<Pointer<Click>>,
-    mut pointer_drag_start: EventWriter<Pointer<DragStart>>,
-    mut pointer_drag_end: EventWriter<Pointer<DragEnd>>,
-    mut pointer_drag: EventWriter<Pointer<Drag>>,
+    (mut pointer_drag_start, mut pointer_drag, mut pointer_drag_end): (
+        EventWriter<Pointer<DragStart>>,
+        EventWriter<Pointer<Drag>>,
+        EventWriter<Pointer<DragEnd>>,
+    ),
 ) {
     let pointer_location = |pointer_id: PointerId| {
         pointer_map
@@ -379,13 +953,29 @@ pub fn send_click_and_drag_events(
             .and_then(|pointer| pointer.location.clone())
     };
 
+    // Forget entities that were despawned while being pressed or dragged.
+    for down_list in down_map.values_mut() {
+        down_list.retain(|entity, _| entities.contains(*entity));
+    }
+    for drag_list in drag_map.values_mut() {
+        drag_list.retain(|entity, _| entities.contains(*entity));
+    }
+
+    let now = time.elapsed();
+    let input_move = new_moves(&input_move);
+    let mut remaining_moves = count_moves(&input_move);
+
     // Triggers during movement even if not over an entity
     for InputMove {
         pointer_id,
         location,
         delta: _,
-    } in input_move.read().cloned()
+    } in input_move
     {
+        let remaining = remaining_moves.entry(pointer_id).or_default();
+        let remaining_for_pointer = *remaining;
+        *remaining = remaining.saturating_sub(1);
+
         for button in PointerButton::iter() {
             let Some(down_list) = down_map.get(&(pointer_id, button)) else {
                 continue;
@@ -401,6 +991,8 @@ pub fn send_click_and_drag_events(
                     DragEntry {
                         start_pos: down.pointer_location.position,
                         latest_pos: down.pointer_location.position,
+                        latest_time: now.saturating_sub(time.delta()),
+                        camera: down.hit.camera,
                     },
                 );
                 pointer_drag_start.send(Pointer::new(
@@ -415,12 +1007,25 @@ pub fn send_click_and_drag_events(
             }
 
             for (dragged_entity, drag) in drag_list.iter_mut() {
+                let distance =
+                    drag_limits.apply(*dragged_entity, drag, location.position - drag.start_pos);
+                let previous_distance =
+                    drag_limits.apply(*dragged_entity, drag, drag.latest_pos - drag.start_pos);
                 let drag_event = Drag {
                     button,
-                    distance: location.position - drag.start_pos,
-                    delta: location.position - drag.latest_pos,
+                    distance,
+                    delta: distance - previous_distance,
+                    delta_time: split_delta_time(&mut drag.latest_time, now, remaining_for_pointer),
                 };
                 drag.latest_pos = location.position;
+                if location.position.distance(drag.start_pos) > click_settings.drag_tolerance {
+                    dragged_since_down.insert((pointer_id, button));
+                    gesture_transitions.send_batch(gesture_map.transition(
+                        pointer_id,
+                        button,
+                        GestureState::Dragging,
+                    ));
+                }
                 pointer_drag.send(Pointer::new(
                     pointer_id,
                     location.clone(),
@@ -431,19 +1036,36 @@ pub fn send_click_and_drag_events(
         }
     }
 
+    // Triggers when button is pressed over an entity. Presses are recorded before releases are
+    // processed, so a button pressed and released in the same frame still produces a click.
+    for event in pointer_down.read() {
+        let button = event.button;
+        let down_button_entity_map = down_map.entry((event.pointer_id, button)).or_default();
+        down_button_entity_map.insert(event.target, event.clone());
+        if gesture_map.get_state(event.pointer_id, button) == GestureState::Idle {
+            gesture_transitions.send_batch(gesture_map.transition(
+                event.pointer_id,
+                button,
+                GestureState::Pressed,
+            ));
+        }
+    }
+
     // Triggers when button is released over an entity
     for Pointer {
         pointer_id,
         pointer_location,
         target,
-        event: Up { button, hit },
+        event: Up { button, hit, .. },
     } in pointer_up.read().cloned()
     {
-        // Can't have a click without the button being pressed down first
+        // Can't have a click without the button being pressed down first, and releasing the
+        // button at the end of a drag is not a click.
         if down_map
             .get(&(pointer_id, button))
             .and_then(|down| down.get(&target))
             .is_some()
+            && !dragged_since_down.contains(&(pointer_id, button))
         {
             pointer_click.send(Pointer::new(
                 pointer_id,
@@ -454,19 +1076,18 @@ pub fn send_click_and_drag_events(
         }
     }
 
-    // Triggers when button is pressed over an entity
-    for event in pointer_down.read() {
-        let button = event.button;
-        let down_button_entity_map = down_map.entry((event.pointer_id, button)).or_default();
-        down_button_entity_map.insert(event.target, event.clone());
-    }
-
     // Triggered for all button presses
     for press in input_presses.read() {
         if press.direction != pointer::PressDirection::Up {
             continue; // We are only interested in button releases
         }
         down_map.insert((press.pointer_id, press.button), HashMap::new());
+        dragged_since_down.remove(&(press.pointer_id, press.button));
+        gesture_transitions.send_batch(gesture_map.transition(
+            press.pointer_id,
+            press.button,
+            GestureState::Idle,
+        ));
         let Some(drag_list) = drag_map.insert((press.pointer_id, press.button), HashMap::new())
         else {
             continue;
@@ -479,33 +1100,32 @@ pub fn send_click_and_drag_events(
             continue;
         };
 
-        for (drag_target, drag) in drag_list {
+        pointer_drag_end.send_batch(drag_list.into_iter().map(|(drag_target, drag)| {
             let drag_end = DragEnd {
                 button: press.button,
-                distance: drag.latest_pos - drag.start_pos,
+                distance: drag_limits.apply(drag_target, &drag, drag.latest_pos - drag.start_pos),
             };
-            pointer_drag_end.send(Pointer::new(
-                press.pointer_id,
-                location.clone(),
-                drag_target,
-                drag_end,
-            ));
-        }
+            Pointer::new(press.pointer_id, location.clone(), drag_target, drag_end)
+        }));
     }
 }
 
+/// Maps pointers to the entities they are dragging over, with the latest [`HitData`] of each. This
+/// can be used to highlight the drop targets under a pointer during a drag.
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct DragOverMap(pub HashMap<(PointerId, PointerButton), HashMap<Entity, HitData>>);
+
 /// Uses pointer events to determine when drag-over events occur
 pub fn send_drag_over_events(
     // Input
     drag_map: Res<DragMap>,
+    payloads: Query<&DragPayload>,
     mut pointer_over: EventReader<Pointer<Over>>,
     mut pointer_move: EventReader<Pointer<Move>>,
     mut pointer_out: EventReader<Pointer<Out>>,
     mut pointer_drag_end: EventReader<Pointer<DragEnd>>,
-    // Local
-    mut drag_over_map: Local<HashMap<(PointerId, PointerButton), HashMap<Entity, HitData>>>,
-
     // Output
+    mut drag_over_map: ResMut<DragOverMap>,
     mut pointer_drag_enter: EventWriter<Pointer<DragEnter>>,
     mut pointer_drag_over: EventWriter<Pointer<DragOver>>,
     mut pointer_drag_leave: EventWriter<Pointer<DragLeave>>,
@@ -516,7 +1136,7 @@ pub fn send_drag_over_events(
         pointer_id,
         pointer_location,
         target,
-        event: Over { hit },
+        event: Over { hit, .. },
     } in pointer_over.read().cloned()
     {
         for button in PointerButton::iter() {
@@ -534,6 +1154,7 @@ pub fn send_drag_over_events(
                     button,
                     dragged: *drag_target,
                     hit: hit.clone(),
+                    payload: payloads.get(*drag_target).ok().cloned(),
                 };
                 pointer_drag_enter.send(Pointer::new(
                     pointer_id,
@@ -550,7 +1171,7 @@ pub fn send_drag_over_events(
         pointer_id,
         pointer_location,
         target,
-        event: Move { hit, delta: _ },
+        event: Move { hit, .. },
     } in pointer_move.read().cloned()
     {
         for button in PointerButton::iter() {
@@ -570,6 +1191,7 @@ pub fn send_drag_over_events(
                         button,
                         dragged: *drag_target,
                         hit: hit.clone(),
+                        payload: payloads.get(*drag_target).ok().cloned(),
                     },
                 ));
             }
@@ -609,6 +1231,7 @@ pub fn send_drag_over_events(
                     button,
                     dropped: target,
                     hit: hit.clone(),
+                    payload: payloads.get(target).ok().cloned(),
                 },
             ));
         }
@@ -647,3 +1270,338 @@ pub fn send_drag_over_events(
         }
     }
 }
+
+/// The state of a button being held down over an entity, used by [`send_hold_repeat_events`].
+#[derive(Debug, Clone)]
+pub struct HoldEntry {
+    /// How long the button has been held down.
+    pub held_for: Duration,
+    /// The value of `held_for` at which the next [`HoldRepeat`] event should be sent.
+    pub next_repeat: Duration,
+    /// The number of [`HoldRepeat`] events sent so far.
+    pub count: u32,
+}
+
+/// Sends [`HoldRepeat`] events while a pointer button is held down over the same entity.
+pub fn send_hold_repeat_events(
+    // Input
+    time: Res<Time>,
+    settings: Res<HoldRepeatSettings>,
+    hover_map: Res<HoverMap>,
+    pointer_map: Res<PointerMap>,
+    pointers: Query<&PointerLocation>,
+    mut pointer_down: EventReader<Pointer<Down>>,
+    mut input_presses: EventReader<InputPress>,
+    // Local
+    mut held: Local<HashMap<(PointerId, PointerButton, Entity), HoldEntry>>,
+    // Output
+    mut pointer_hold_repeat: EventWriter<Pointer<HoldRepeat>>,
+) {
+    // Downs are added before releases are applied, so a button pressed and released within the
+    // same frame is never held.
+    for down in pointer_down.read() {
+        held.insert(
+            (down.pointer_id, down.button, down.target),
+            HoldEntry {
+                held_for: Duration::ZERO,
+                next_repeat: settings.initial_delay,
+                count: 0,
+            },
+        );
+    }
+
+    for press in input_presses.read() {
+        if press.direction == pointer::PressDirection::Up {
+            held.retain(|(pointer_id, button, _), _| {
+                *pointer_id != press.pointer_id || *button != press.button
+            });
+        }
+    }
+
+    held.retain(|(pointer_id, button, target), entry| {
+        // Stop repeating as soon as the pointer is no longer over the held entity.
+        let Some(hit) = hover_map
+            .get(pointer_id)
+            .and_then(|hovered| hovered.get(target))
+        else {
+            return false;
+        };
+        let Some(location) = pointer_map
+            .get_entity(*pointer_id)
+            .and_then(|entity| pointers.get(entity).ok())
+            .and_then(|pointer| pointer.location.clone())
+        else {
+            return false;
+        };
+
+        entry.held_for += time.delta();
+        if entry.held_for >= entry.next_repeat {
+            entry.next_repeat += settings.interval;
+            entry.count += 1;
+            pointer_hold_repeat.send(Pointer::new(
+                *pointer_id,
+                location,
+                *target,
+                HoldRepeat {
+                    button: *button,
+                    count: entry.count,
+                    hit: hit.clone(),
+                },
+            ));
+        }
+        true
+    });
+}
+
+/// Sends a [`ContextMenu`] event for every [`Click`] with the [`ContextMenuSettings::button`].
+pub fn send_context_menu_events(
+    settings: Res<ContextMenuSettings>,
+    mut clicks: EventReader<Pointer<Click>>,
+    mut context_menus: EventWriter<Pointer<ContextMenu>>,
+) {
+    if !settings.is_enabled {
+        clicks.clear();
+        return;
+    }
+    context_menus.send_batch(
+        clicks
+            .read()
+            .filter(|click| click.button == settings.button)
+            .map(|click| {
+                Pointer::new(
+                    click.pointer_id,
+                    click.pointer_location.clone(),
+                    click.target,
+                    ContextMenu {
+                        hit: click.hit.clone(),
+                    },
+                )
+            }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::prelude::*;
+    use bevy_render::camera::RenderTarget;
+    use bevy_window::WindowRef;
+
+    use super::*;
+    use crate::{
+        backend::PointerHits,
+        focus::{ModalStack, PickingModal},
+        CorePlugin, InteractionPlugin, PointerCoreBundle,
+    };
+
+    const POINTER: PointerId = PointerId::Mouse;
+
+    fn location(position: Vec2) -> Location {
+        Location {
+            target: RenderTarget::Window(WindowRef::Entity(Entity::PLACEHOLDER))
+                .normalize(None)
+                .unwrap(),
+            position,
+        }
+    }
+
+    /// An app with the core picking plugins, and a mouse pointer hovering `target`.
+    fn test_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((CorePlugin, InteractionPlugin))
+            .init_resource::<Time>();
+        app.world_mut()
+            .spawn(PointerCoreBundle::new(POINTER).with_location(location(Vec2::ZERO)));
+        let target = app.world_mut().spawn_empty().id();
+        (app, target)
+    }
+
+    /// Runs a frame `delta` after the last one, with `target` under the pointer.
+    fn update(app: &mut App, target: Entity, delta: Duration) {
+        app.world_mut().resource_mut::<Time>().advance_by(delta);
+        let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+        app.world_mut()
+            .send_event(PointerHits::new(POINTER, vec![(target, hit)], 0.0));
+        app.update();
+    }
+
+    fn press(app: &mut App, direction: PressDirection) {
+        let press = match direction {
+            PressDirection::Down => InputPress::new_down(POINTER, PointerButton::Primary),
+            PressDirection::Up => InputPress::new_up(POINTER, PointerButton::Primary),
+        };
+        app.world_mut().send_event(press);
+    }
+
+    #[test]
+    fn confined_move_events_are_clamped() {
+        let (mut app, target) = test_app();
+        let pointer = app
+            .world_mut()
+            .query_filtered::<Entity, With<PointerId>>()
+            .single(app.world());
+        let bounds = Rect::new(0.0, 0.0, 10.0, 10.0);
+        app.world_mut()
+            .entity_mut(pointer)
+            .insert(pointer::PointerConfine::new(bounds));
+        update(&mut app, target, Duration::ZERO);
+
+        let delta = Vec2::new(25.0, 5.0);
+        let input_move = InputMove::new(POINTER, location(delta), delta);
+        app.world_mut().send_event(input_move);
+        update(&mut app, target, Duration::ZERO);
+
+        let moves = app.world().resource::<Events<Pointer<Move>>>();
+        let event = moves.iter_current_update_events().last().unwrap();
+        assert_eq!(event.pointer_location.position, Vec2::new(10.0, 5.0));
+        assert_eq!(event.delta, Vec2::new(10.0, 5.0));
+    }
+
+    #[test]
+    fn hits_from_two_backends_send_one_event() {
+        let (mut app, target) = test_app();
+        // A second backend reports a hit on the same entity, with more data.
+        let position = Some(Vec3::ONE);
+        let hit = HitData::new(Entity::PLACEHOLDER, 0.0, position, Some(Vec3::Z));
+        app.world_mut()
+            .send_event(PointerHits::new(POINTER, vec![(target, hit.clone())], 0.0));
+        press(&mut app, PressDirection::Down);
+        update(&mut app, target, Duration::ZERO);
+
+        let overs = app.world().resource::<Events<Pointer<Over>>>();
+        assert_eq!(overs.len(), 1);
+        let downs = app.world().resource::<Events<Pointer<Down>>>();
+        let downs: Vec<_> = downs.iter_current_update_events().collect();
+        assert_eq!(downs.len(), 1);
+        assert_eq!(downs[0].hit, hit);
+    }
+
+    #[test]
+    fn mesh_hit_fills_in_ui_hit() {
+        let (mut app, target) = test_app();
+        // A mesh backend reports a hit with spatial data on a lower layer and at a greater depth
+        // than the UI hit sent by `update`.
+        let position = Some(Vec3::ONE);
+        let hit = HitData::new(Entity::PLACEHOLDER, 10.0, position, Some(Vec3::Z));
+        app.world_mut()
+            .send_event(PointerHits::new(POINTER, vec![(target, hit)], -0.5));
+        update(&mut app, target, Duration::ZERO);
+
+        // The depth of the UI hit is kept, with the position and normal of the mesh hit.
+        let overs = app.world().resource::<Events<Pointer<Over>>>();
+        let overs: Vec<_> = overs.iter_current_update_events().collect();
+        assert_eq!(overs.len(), 1);
+        let expected = HitData::new(Entity::PLACEHOLDER, 0.0, position, Some(Vec3::Z));
+        assert_eq!(overs[0].hit, expected);
+    }
+
+    #[test]
+    fn entry_edge_is_found_from_position() {
+        let (mut app, target) = test_app();
+        let previous = Vec2::new(22.0, 9.0);
+        app.world_mut()
+            .send_event(InputMove::new(POINTER, location(previous), previous));
+        app.update();
+
+        // The pointer moves mostly downwards, but crosses the right edge of the bounds.
+        let position = Vec2::new(19.0, 19.0);
+        app.world_mut().send_event(InputMove::new(
+            POINTER,
+            location(position),
+            position - previous,
+        ));
+        let bounds = Rect::new(10.0, 10.0, 20.0, 20.0);
+        let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None).with_bounds(bounds);
+        app.world_mut()
+            .send_event(PointerHits::new(POINTER, vec![(target, hit)], 0.0));
+        app.update();
+
+        let overs = app.world().resource::<Events<Pointer<Over>>>();
+        let overs: Vec<_> = overs.iter_current_update_events().collect();
+        assert_eq!(overs.len(), 1);
+        assert_eq!(overs[0].entry_edge, Some(Edge::Right));
+    }
+
+    /// Presses and releases the primary button over `target`, moving the pointer by `distance`
+    /// while it is pressed, and returns the number of clicks.
+    fn clicks_after_moving(distance: Vec2) -> usize {
+        let (mut app, target) = test_app();
+        update(&mut app, target, Duration::ZERO);
+        press(&mut app, PressDirection::Down);
+        update(&mut app, target, Duration::ZERO);
+
+        let input_move = InputMove::new(POINTER, location(distance), distance);
+        app.world_mut().send_event(input_move);
+        update(&mut app, target, Duration::ZERO);
+        press(&mut app, PressDirection::Up);
+        update(&mut app, target, Duration::ZERO);
+
+        let clicks = app.world().resource::<Events<Pointer<Click>>>();
+        clicks.iter_current_update_events().count()
+    }
+
+    #[test]
+    fn small_movement_still_clicks() {
+        assert_eq!(clicks_after_moving(Vec2::new(0.5, 0.0)), 1);
+        assert_eq!(clicks_after_moving(Vec2::new(3.0, 0.0)), 1);
+        assert_eq!(clicks_after_moving(Vec2::new(10.0, 0.0)), 0);
+    }
+
+    #[test]
+    fn modals_added_together_are_stacked_by_entity() {
+        let (mut app, _) = test_app();
+        let first = app.world_mut().spawn_empty().id();
+        let second = app.world_mut().spawn_empty().id();
+        // Inserting in reverse order also reverses the order of the modals in their table.
+        app.world_mut().entity_mut(second).insert(PickingModal);
+        app.world_mut().entity_mut(first).insert(PickingModal);
+        app.update();
+
+        let modal_stack = app.world().resource::<ModalStack>();
+        assert_eq!(**modal_stack, vec![first, second]);
+    }
+
+    #[test]
+    fn same_frame_press_and_release_clicks() {
+        let (mut app, target) = test_app();
+        update(&mut app, target, Duration::ZERO);
+
+        press(&mut app, PressDirection::Down);
+        press(&mut app, PressDirection::Up);
+        update(&mut app, target, Duration::ZERO);
+
+        let clicks = app.world().resource::<Events<Pointer<Click>>>();
+        let clicks: Vec<_> = clicks.iter_current_update_events().collect();
+        assert_eq!(clicks.len(), 1);
+        assert_eq!(clicks[0].target, target);
+        assert_eq!(clicks[0].button, PointerButton::Primary);
+    }
+
+    #[test]
+    fn same_frame_press_and_release_does_not_repeat() {
+        let (mut app, target) = test_app();
+        update(&mut app, target, Duration::ZERO);
+
+        press(&mut app, PressDirection::Down);
+        press(&mut app, PressDirection::Up);
+        update(&mut app, target, Duration::ZERO);
+        for _ in 0..10 {
+            update(&mut app, target, Duration::from_millis(100));
+        }
+
+        let repeats = app.world().resource::<Events<Pointer<HoldRepeat>>>();
+        assert!(repeats.is_empty());
+    }
+
+    #[test]
+    fn held_press_repeats() {
+        let (mut app, target) = test_app();
+        update(&mut app, target, Duration::ZERO);
+
+        press(&mut app, PressDirection::Down);
+        update(&mut app, target, Duration::ZERO);
+        update(&mut app, target, Duration::from_millis(600));
+
+        let repeats = app.world().resource::<Events<Pointer<HoldRepeat>>>();
+        assert_eq!(repeats.len(), 1);
+    }
+}
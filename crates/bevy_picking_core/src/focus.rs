@@ -1,18 +1,24 @@
 //! Determines which entities are being hovered by which pointers.
 
-use std::{collections::BTreeMap, fmt::Debug};
+use std::{cmp::Reverse, collections::BTreeMap, fmt::Debug, time::Duration};
 
 use crate::{
     backend::{self, HitData},
     events::PointerCancel,
-    pointer::{PointerId, PointerInteraction, PointerPress},
-    Pickable,
+    pointer::{PointerId, PointerInteraction, PointerLocation, PointerMap, PointerPress},
+    IgnoreSubtree, PickPriority, Pickable, PointerFilter,
 };
 
 use bevy_derive::{Deref, DerefMut};
-use bevy_ecs::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    system::{SystemParam, SystemState},
+};
+use bevy_hierarchy::{HierarchyQueryExt, Parent};
 use bevy_math::FloatOrd;
 use bevy_reflect::prelude::*;
+use bevy_render::camera::NormalizedRenderTarget;
+use bevy_time::Time;
 use bevy_utils::HashMap;
 
 type DepthSortedHits = Vec<(Entity, HitData)>;
@@ -28,6 +34,16 @@ type LayerMap = BTreeMap<PickLayer, DepthSortedHits>;
 /// this data structure is used to sort entities by layer then depth for every pointer.
 type OverMap = HashMap<PointerId, LayerMap>;
 
+/// Maps pointers to every entity under them, as reported by backends, before [`Pickable`]
+/// blocking and [`PickingModal`] filtering are applied. Hits are grouped by layer, and sorted by
+/// depth within each layer, nearest first.
+///
+/// Unlike the [`HoverMap`], this includes entities that are occluded or blocked by entities above
+/// them. This is useful for tools that list everything under the cursor, for example to debug why
+/// an entity is not being hovered. Entities in an [`IgnoreSubtree`] are not included.
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct PointerOverMap(pub OverMap);
+
 /// The source of truth for all hover state. This is used to determine what events to send, and what
 /// state components should be in.
 ///
@@ -56,17 +72,77 @@ pub struct HoverMap(pub HashMap<PointerId, HashMap<Entity, HitData>>);
 #[derive(Debug, Deref, DerefMut, Default, Resource)]
 pub struct PreviousHoverMap(pub HashMap<PointerId, HashMap<Entity, HitData>>);
 
+/// Maps hits on this entity to other, logical entities. This is useful when many logical objects
+/// are rendered with a single mesh entity, for example with instancing or manual batching, and a
+/// hit needs to be attributed to one of those objects instead of the mesh entity.
+///
+/// The resolver is run on every hit reported for this entity, before hover focus is computed. If it
+/// returns `None`, the hit is attributed to this entity.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InstancePickable {
+    /// Maps a hit on this entity to the entity that should be considered hit, e.g. by using
+    /// [`HitData::triangle_index`] or [`HitData::position`].
+    pub resolve: fn(hit: &HitData) -> Option<Entity>,
+}
+
+impl InstancePickable {
+    /// Create an [`InstancePickable`] with the supplied resolver.
+    pub fn new(resolve: fn(hit: &HitData) -> Option<Entity>) -> Self {
+        Self { resolve }
+    }
+}
+
+/// Marks this entity and its descendants as a modal, like a dialog. While any modal exists, only
+/// the topmost modal subtree can be hovered, and hits on all other entities are discarded. This
+/// blocks interaction with everything behind the modal, without needing to change the [`Pickable`]
+/// of every other entity.
+///
+/// Modals form a stack: the most recently added modal is the topmost one. When it is removed or
+/// despawned, the previous modal becomes active again. Modals added in the same frame are stacked
+/// in [`Entity`] order, so the one with the greatest [`Entity`] is on top. See [`ModalStack`].
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
+pub struct PickingModal;
+
+/// The stack of [`PickingModal`] entities, ordered from bottom to top.
+#[derive(Debug, Deref, Default, Resource)]
+pub struct ModalStack(Vec<Entity>);
+
+impl ModalStack {
+    /// The topmost modal entity, if any. Only this entity and its descendants can be hovered.
+    pub fn top(&self) -> Option<Entity> {
+        self.0.last().copied()
+    }
+}
+
+/// Updates the [`ModalStack`] when [`PickingModal`] components are added or removed.
+pub fn update_modal_stack(
+    mut modal_stack: ResMut<ModalStack>,
+    added: Query<Entity, Added<PickingModal>>,
+    mut removed: RemovedComponents<PickingModal>,
+) {
+    for entity in removed.read() {
+        modal_stack.0.retain(|modal| *modal != entity);
+    }
+    // Query order depends on archetype and table layout, so sort to keep the stack deterministic.
+    let mut added: Vec<Entity> = added.iter().collect();
+    added.sort();
+    for entity in added {
+        modal_stack.0.retain(|modal| *modal != entity);
+        modal_stack.0.push(entity);
+    }
+}
+
 /// Coalesces all data from inputs and backends to generate a map of the currently hovered entities.
 /// This is the final focusing step to determine which entity the pointer is hovering over.
 pub fn update_focus(
     // Inputs
-    pickable: Query<&Pickable>,
+    focus: FocusParams,
     pointers: Query<&PointerId>,
     mut under_pointer: EventReader<backend::PointerHits>,
     mut cancellations: EventReader<PointerCancel>,
-    // Local
-    mut over_map: Local<OverMap>,
     // Output
+    mut over_map: ResMut<PointerOverMap>,
     mut hover_map: ResMut<HoverMap>,
     mut previous_hover_map: ResMut<PreviousHoverMap>,
 ) {
@@ -76,11 +152,106 @@ pub fn update_focus(
         &mut over_map,
         &pointers,
     );
-    build_over_map(&mut under_pointer, &mut over_map, &mut cancellations);
-    build_hover_map(&pointers, pickable, &over_map, &mut hover_map);
+    let cancelled: Vec<PointerId> = cancellations.read().map(|p| p.pointer_id).collect();
+    focus.compute(
+        pointers.iter().copied(),
+        under_pointer.read(),
+        &cancelled,
+        &mut over_map,
+        &mut hover_map,
+    );
+}
+
+/// Computes the hover state of the `pointers` from explicitly supplied backend `hits`, instead of
+/// the [`PointerHits`](backend::PointerHits) events of this frame. No picking state is modified.
+///
+/// This makes it possible to run focus at a chosen time, for example in a networked app that
+/// drives pointers from buffered network input, and needs deterministic hover state for a specific
+/// tick during prediction or rollback. The [`ModalStack`] resource must exist.
+///
+/// This initializes a [`SystemState`] on every call. To avoid that, store a
+/// `SystemState<FocusParams>` and call [`FocusParams::compute`].
+pub fn update_focus_for(
+    world: &mut World,
+    pointers: &[PointerId],
+    hits: &[backend::PointerHits],
+) -> HoverMap {
+    let mut state = SystemState::<FocusParams>::new(world);
+    let focus = state.get(world);
+    let mut over_map = PointerOverMap::default();
+    let mut hover_map = HoverMap::default();
+    focus.compute(
+        pointers.iter().copied(),
+        hits,
+        &[],
+        &mut over_map,
+        &mut hover_map,
+    );
+    hover_map
+}
+
+/// The entities and resources read to compute focus from backend hits. Used by [`update_focus`],
+/// and [`update_focus_for`].
+#[derive(SystemParam)]
+pub struct FocusParams<'w, 's> {
+    pickable: Query<'w, 's, &'static Pickable>,
+    instances: Query<'w, 's, &'static InstancePickable>,
+    priorities: Query<'w, 's, &'static PickPriority>,
+    ignored: Query<'w, 's, (), With<IgnoreSubtree>>,
+    filters: Query<'w, 's, &'static PointerFilter>,
+    parents: Query<'w, 's, &'static Parent>,
+    modal_stack: Res<'w, ModalStack>,
 }
 
-/// Clear non-empty local maps, reusing allocated memory.
+impl FocusParams<'_, '_> {
+    /// Adds the entities under each pointer, reported in `hits`, to the `over_map`, and the
+    /// entities hovered by each of the `pointers` to the `hover_map`. Hits of `cancelled` pointers
+    /// are ignored. The maps are not cleared first.
+    ///
+    /// This only reads the world, it does not read events or modify resources, so it can be used
+    /// to compute focus from any set of hits.
+    pub fn compute<'a>(
+        &self,
+        pointers: impl IntoIterator<Item = PointerId>,
+        hits: impl IntoIterator<Item = &'a backend::PointerHits>,
+        cancelled: &[PointerId],
+        over_map: &mut PointerOverMap,
+        hover_map: &mut HoverMap,
+    ) {
+        let mut ignored_cache = HashMap::new();
+        let mut is_ignored = |entity: Entity| {
+            is_subtree_ignored(entity, &self.ignored, &self.parents, &mut ignored_cache)
+        };
+        build_over_map(
+            hits,
+            over_map,
+            cancelled,
+            &self.instances,
+            &self.priorities,
+            &mut is_ignored,
+        );
+        let is_in_modal = |entity: Entity| match self.modal_stack.top() {
+            Some(modal) => {
+                entity == modal
+                    || self
+                        .parents
+                        .iter_ancestors(entity)
+                        .any(|parent| parent == modal)
+            }
+            None => true,
+        };
+        build_hover_map(
+            pointers,
+            &self.pickable,
+            &self.filters,
+            over_map,
+            is_in_modal,
+            hover_map,
+        );
+    }
+}
+
+/// Clear non-empty maps, reusing allocated memory.
 fn reset_maps(
     hover_map: &mut HoverMap,
     previous_hover_map: &mut PreviousHoverMap,
@@ -106,15 +277,16 @@ fn reset_maps(
 }
 
 /// Build an ordered map of entities that are under each pointer
-fn build_over_map(
-    backend_events: &mut EventReader<backend::PointerHits>,
-    pointer_over_map: &mut Local<OverMap>,
-    pointer_cancel: &mut EventReader<PointerCancel>,
+fn build_over_map<'a>(
+    backend_hits: impl IntoIterator<Item = &'a backend::PointerHits>,
+    pointer_over_map: &mut OverMap,
+    cancelled_pointers: &[PointerId],
+    instances: &Query<&InstancePickable>,
+    priorities: &Query<&PickPriority>,
+    is_ignored: &mut impl FnMut(Entity) -> bool,
 ) {
-    let cancelled_pointers: Vec<PointerId> = pointer_cancel.read().map(|p| p.pointer_id).collect();
-
-    for entities_under_pointer in backend_events
-        .read()
+    for entities_under_pointer in backend_hits
+        .into_iter()
         .filter(|e| !cancelled_pointers.contains(&e.pointer))
     {
         let pointer = entities_under_pointer.pointer;
@@ -122,44 +294,191 @@ fn build_over_map(
             .entry(pointer)
             .or_insert_with(BTreeMap::new);
         for (entity, pick_data) in entities_under_pointer.picks.iter() {
-            let layer = entities_under_pointer.order;
+            if is_ignored(*entity) {
+                continue;
+            }
+            let layer = pick_data.layer.unwrap_or(entities_under_pointer.order);
+            let entity = instances
+                .get(*entity)
+                .ok()
+                .and_then(|instance| (instance.resolve)(pick_data))
+                .unwrap_or(*entity);
             let hits = layer_map.entry(FloatOrd(layer)).or_insert_with(Vec::new);
-            hits.push((*entity, pick_data.clone()));
+            hits.push((entity, pick_data.clone()));
         }
     }
 
     for layers in pointer_over_map.values_mut() {
         for hits in layers.values_mut() {
-            hits.sort_by_key(|(_, hit)| FloatOrd(hit.depth));
+            // Sorting is stable, and higher priority entities come first when depths are equal.
+            hits.sort_by_key(|(entity, hit)| {
+                let priority = priorities.get(*entity).copied().unwrap_or_default();
+                (FloatOrd(hit.depth), Reverse(priority))
+            });
+        }
+    }
+}
+
+/// Returns `true` if this entity or any of its ancestors has an [`IgnoreSubtree`] component.
+/// Results are stored in the `cache` for every entity visited, so each hierarchy is only walked
+/// once.
+fn is_subtree_ignored(
+    entity: Entity,
+    ignored: &Query<(), With<IgnoreSubtree>>,
+    parents: &Query<&Parent>,
+    cache: &mut HashMap<Entity, bool>,
+) -> bool {
+    if ignored.is_empty() {
+        return false;
+    }
+    let mut visited = Vec::new();
+    let mut current = Some(entity);
+    let mut is_ignored = false;
+    while let Some(entity) = current {
+        if let Some(cached) = cache.get(&entity) {
+            is_ignored = *cached;
+            break;
+        }
+        visited.push(entity);
+        if ignored.contains(entity) {
+            is_ignored = true;
+            break;
         }
+        current = parents.get(entity).ok().map(Parent::get);
+    }
+    for entity in visited {
+        cache.insert(entity, is_ignored);
     }
+    is_ignored
+}
+
+/// Delays hovering an entity until a pointer has dwelled on it for [`HoverDelay::duration`], so
+/// that passing over entities does not trigger hover effects. Entities stop being hovered
+/// immediately, so [`Out`](crate::events::Out) events are not delayed. Disabled when zero, which
+/// is the default.
+///
+/// Entities waiting for the delay are not in the [`HoverMap`], so they receive no pointer events
+/// and are not [`PickingInteraction::Hovered`]. To avoid losing presses and drags, the delay is
+/// skipped while any button of the pointer is pressed.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct HoverDelay {
+    /// How long a pointer needs to stay on an entity before it is hovered.
+    pub duration: Duration,
+}
+
+/// Removes entities from the [`HoverMap`] until they have been under a pointer for the
+/// [`HoverDelay`].
+pub fn apply_hover_delay(
+    // Inputs
+    time: Res<Time>,
+    hover_delay: Res<HoverDelay>,
+    pointer_map: Res<PointerMap>,
+    presses: Query<&PointerPress>,
+    previous_hover_map: Res<PreviousHoverMap>,
+    // Local
+    mut pending: Local<HashMap<(PointerId, Entity), Duration>>,
+    // Output
+    mut hover_map: ResMut<HoverMap>,
+) {
+    if hover_delay.duration.is_zero() {
+        pending.clear();
+        return;
+    }
+    let now = time.elapsed();
+    let mut still_pending = HashMap::new();
+    for (pointer_id, hovered) in hover_map.iter_mut() {
+        let is_pressed = pointer_map
+            .get_entity(*pointer_id)
+            .and_then(|entity| presses.get(entity).ok())
+            .is_some_and(PointerPress::is_any_pressed);
+        if is_pressed {
+            continue;
+        }
+        let previous = previous_hover_map.get(pointer_id);
+        hovered.retain(|entity, _| {
+            if previous.is_some_and(|previous| previous.contains_key(entity)) {
+                return true;
+            }
+            let key = (*pointer_id, *entity);
+            let since = pending.get(&key).copied().unwrap_or(now);
+            if now.saturating_sub(since) >= hover_delay.duration {
+                return true;
+            }
+            still_pending.insert(key, since);
+            false
+        });
+    }
+    // Forget entities that the pointer left before the delay elapsed.
+    *pending = still_pending;
 }
 
 /// Build an unsorted set of hovered entities, accounting for depth, layer, and [`Pickable`]. Note
 /// that unlike the pointer map, this uses [`Pickable`] to determine if lower entities receive hover
 /// focus. Often, only a single entity per pointer will be hovered.
+///
+/// An entity is only hovered once per pointer, even if multiple backends reported hits on it. The
+/// hits are visited from the highest layer and nearest depth first, so the first hit is kept,
+/// unless a later hit has the same depth and more information, like a position or normal. A later
+/// hit from the same camera at another depth or layer only fills in the position and normal the
+/// kept hit is missing, so a mesh hit can add spatial data to a UI hit on the same entity, while
+/// the depth and layer of the UI hit are kept.
+///
+/// Hits on entities outside of the topmost [`PickingModal`], and on entities whose
+/// [`PointerFilter`] does not allow the pointer, are discarded.
 fn build_hover_map(
-    pointers: &Query<&PointerId>,
-    pickable: Query<&Pickable>,
-    over_map: &Local<OverMap>,
+    pointers: impl IntoIterator<Item = PointerId>,
+    pickable: &Query<&Pickable>,
+    filters: &Query<&PointerFilter>,
+    over_map: &OverMap,
+    is_in_modal: impl Fn(Entity) -> bool,
     // Output
     hover_map: &mut HoverMap,
 ) {
-    for pointer_id in pointers.iter() {
-        let pointer_entity_set = hover_map.entry(*pointer_id).or_insert_with(HashMap::new);
-        if let Some(layer_map) = over_map.get(pointer_id) {
+    // The number of optional fields of a hit that are filled in.
+    let richness = |hit: &HitData| hit.position.is_some() as u8 + hit.normal.is_some() as u8;
+
+    for pointer_id in pointers {
+        let pointer_entity_set = hover_map.entry(pointer_id).or_insert_with(HashMap::new);
+        let mut insert = |entity: Entity, hit: &HitData| {
+            let kept = pointer_entity_set
+                .entry(entity)
+                .or_insert_with(|| hit.clone());
+            // Prefer the hit with the most data when backends tie, e.g. a mesh hit over a UI hit.
+            if kept.depth == hit.depth && richness(hit) > richness(&*kept) {
+                *kept = hit.clone();
+            } else if kept.camera == hit.camera {
+                kept.position = kept.position.or(hit.position);
+                kept.normal = kept.normal.or(hit.normal);
+            }
+        };
+        if let Some(layer_map) = over_map.get(&pointer_id) {
+            // The entity blocking lower entities. Other hits on the blocking entity, reported by
+            // other backends, are still visited so the richest hit can be kept.
+            let mut blocked_by = None;
             // Note we reverse here to start from the highest layer first.
             for (entity, pick_data) in layer_map.values().rev().flatten() {
+                if blocked_by.is_some_and(|blocker| blocker != *entity) || !is_in_modal(*entity) {
+                    continue;
+                }
+                // Entities that filter out this pointer are invisible to it, and don't block.
+                if filters
+                    .get(*entity)
+                    .is_ok_and(|filter| !filter.allows(&pointer_id))
+                {
+                    continue;
+                }
                 if let Ok(pickable) = pickable.get(*entity) {
                     if pickable.is_hoverable {
-                        pointer_entity_set.insert(*entity, pick_data.clone());
+                        insert(*entity, pick_data);
                     }
                     if pickable.should_block_lower {
-                        break;
+                        blocked_by = Some(*entity);
                     }
                 } else {
-                    pointer_entity_set.insert(*entity, pick_data.clone()); // Emit events by default
-                    break; // Entities block by default so we break out of the loop
+                    // Emit events by default
+                    insert(*entity, pick_data);
+                    blocked_by = Some(*entity); // Entities block by default
                 }
             }
         }
@@ -187,22 +506,74 @@ pub enum PickingInteraction {
     None = 0,
 }
 
-/// Uses pointer events to update [`PointerInteraction`] and [`PickingInteraction`] components.
+/// Fires when [`update_interactions`] changes the [`PickingInteraction`] of an entity, including
+/// when the component is first inserted.
+///
+/// This is a single stream of interaction changes for all entities, useful for integrating with
+/// libraries that expect callbacks instead of polling `Changed<PickingInteraction>`. Changes made
+/// by [`PropagateInteraction`] are not reported.
+#[derive(Event, Copy, Clone, Eq, PartialEq, Debug, Reflect)]
+pub struct InteractionChanged {
+    /// The entity whose interaction changed.
+    pub entity: Entity,
+    /// The interaction before the change.
+    pub from: PickingInteraction,
+    /// The interaction after the change.
+    pub to: PickingInteraction,
+}
+
+/// A marker component for entities whose [`PickingInteraction`] should reflect the interaction
+/// state of their descendants, as well as their own.
+///
+/// For example, a button made of a parent node with a text and an icon child will normally only
+/// show the child under the pointer as hovered. Adding this marker to the parent will mark the
+/// parent as hovered when any of its descendants are hovered, allowing compound widgets to be
+/// highlighted as a unit. When multiple descendants are interacted with, the state with the highest
+/// precedence is used, as described in [`PickingInteraction`].
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
+pub struct PropagateInteraction;
+
+/// A summary of the [`PickingInteraction`] state of all entities, across all pointers. This is
+/// useful to answer questions like "is the pointer over anything pickable?" without needing to
+/// query every entity.
+///
+/// This resource is only mutated when its value changes, so change detection can be used to react
+/// to transitions.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct GlobalPickState {
+    /// `true` if any entity is [`PickingInteraction::Hovered`] or [`PickingInteraction::Pressed`].
+    pub any_hovered: bool,
+    /// `true` if any entity is [`PickingInteraction::Pressed`].
+    pub any_pressed: bool,
+    /// The number of entities that are hovered or pressed by at least one pointer.
+    pub hovered_count: usize,
+}
+
+/// Uses pointer events to update [`PointerInteraction`] and [`PickingInteraction`] components, as
+/// well as the [`GlobalPickState`].
 pub fn update_interactions(
     // Input
     hover_map: Res<HoverMap>,
     previous_hover_map: Res<PreviousHoverMap>,
     // Outputs
     mut commands: Commands,
+    mut global_state: ResMut<GlobalPickState>,
     mut pointers: Query<(&PointerId, &PointerPress, &mut PointerInteraction)>,
     mut interact: Query<&mut PickingInteraction>,
+    mut interaction_changed: EventWriter<InteractionChanged>,
 ) {
+    // The interaction of each entity before it was cleared, used to send `InteractionChanged`.
+    let mut previous_interactions = HashMap::<Entity, PickingInteraction>::new();
+
     // Clear all previous hover data from pointers and entities
     for (pointer, _, mut pointer_interaction) in &mut pointers {
         pointer_interaction.sorted_entities.clear();
         if let Some(previously_hovered_entities) = previous_hover_map.get(pointer) {
             for entity in previously_hovered_entities.keys() {
                 if let Ok(mut interaction) = interact.get_mut(*entity) {
+                    previous_interactions.entry(*entity).or_insert(*interaction);
                     *interaction = PickingInteraction::None;
                 }
             }
@@ -227,14 +598,36 @@ pub fn update_interactions(
         }
     }
 
+    global_state.set_if_neq(GlobalPickState {
+        any_hovered: !new_interaction_state.is_empty(),
+        any_pressed: new_interaction_state
+            .values()
+            .any(|interaction| *interaction == PickingInteraction::Pressed),
+        hovered_count: new_interaction_state.len(),
+    });
+
     // Take the aggregated entity states and update or insert the component if missing.
+    let mut send_changed = |entity, from, to| {
+        if from != to {
+            interaction_changed.send(InteractionChanged { entity, from, to });
+        }
+    };
     for (hovered_entity, new_interaction) in new_interaction_state.drain() {
         if let Ok(mut interaction) = interact.get_mut(hovered_entity) {
+            let from = previous_interactions
+                .remove(&hovered_entity)
+                .unwrap_or(*interaction);
             *interaction = new_interaction;
+            send_changed(hovered_entity, from, new_interaction);
         } else if let Some(mut entity_commands) = commands.get_entity(hovered_entity) {
             entity_commands.try_insert(new_interaction);
+            send_changed(hovered_entity, PickingInteraction::None, new_interaction);
         }
     }
+    // Entities that are no longer hovered.
+    for (entity, from) in previous_interactions.drain() {
+        send_changed(entity, from, PickingInteraction::None);
+    }
 }
 
 /// Merge the interaction state of this entity into the aggregated map.
@@ -264,3 +657,163 @@ fn merge_interaction_states(
         new_interaction_state.insert(*hovered_entity, new_interaction);
     }
 }
+
+/// Updates the [`PickingInteraction`] of entities marked with [`PropagateInteraction`] to the
+/// aggregate state of themselves and their descendants.
+pub fn propagate_interactions(
+    // Input
+    hover_map: Res<HoverMap>,
+    pointers: Query<(&PointerId, &PointerPress)>,
+    parents: Query<&Parent>,
+    // Outputs
+    mut commands: Commands,
+    mut propagating: Query<(Entity, Option<&mut PickingInteraction>), With<PropagateInteraction>>,
+) {
+    if propagating.is_empty() {
+        return;
+    }
+
+    let mut new_interaction_state = HashMap::<Entity, PickingInteraction>::new();
+    for (pointer, pointer_press) in &pointers {
+        let Some(pointers_hovered_entities) = hover_map.get(pointer) else {
+            continue;
+        };
+        for hovered_entity in pointers_hovered_entities.keys() {
+            for entity in
+                std::iter::once(*hovered_entity).chain(parents.iter_ancestors(*hovered_entity))
+            {
+                if propagating.contains(entity) {
+                    merge_interaction_states(pointer_press, &entity, &mut new_interaction_state);
+                }
+            }
+        }
+    }
+
+    for (entity, interaction) in &mut propagating {
+        let new_interaction = new_interaction_state
+            .get(&entity)
+            .copied()
+            .unwrap_or_default();
+        match interaction {
+            Some(mut interaction) => {
+                interaction.set_if_neq(new_interaction);
+            }
+            None if new_interaction != PickingInteraction::None => {
+                commands.entity(entity).try_insert(new_interaction);
+            }
+            None => {}
+        }
+    }
+}
+
+/// Lists every pointer currently hovering this entity, along with the [`HitData`] for each. This is
+/// the per-entity inverse of the [`HoverMap`], useful for multitouch-aware widgets that need to
+/// know about all pointers interacting with them, like a piano key tracking all fingers on it.
+///
+/// This component is opt-in: it is only updated for entities it has been added to.
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct EntityPointers {
+    /// The pointers hovering this entity, sorted by [`HitData::depth`], nearest first.
+    pub pointers: Vec<(PointerId, HitData)>,
+}
+
+impl EntityPointers {
+    /// Returns `true` if no pointers are hovering this entity.
+    pub fn is_empty(&self) -> bool {
+        self.pointers.is_empty()
+    }
+
+    /// Iterate over the pointers hovering this entity, nearest first.
+    pub fn iter(&self) -> impl Iterator<Item = &(PointerId, HitData)> {
+        self.pointers.iter()
+    }
+}
+
+/// Updates all [`EntityPointers`] components from the [`HoverMap`].
+pub fn update_entity_pointers(
+    hover_map: Res<HoverMap>,
+    mut entity_pointers: Query<(Entity, &mut EntityPointers)>,
+) {
+    if entity_pointers.is_empty() {
+        return;
+    }
+
+    let mut new_pointers = HashMap::<Entity, Vec<(PointerId, HitData)>>::new();
+    for (pointer_id, hovered_entities) in hover_map.iter() {
+        for (entity, hit) in hovered_entities.iter() {
+            if entity_pointers.contains(*entity) {
+                new_pointers
+                    .entry(*entity)
+                    .or_default()
+                    .push((*pointer_id, hit.clone()));
+            }
+        }
+    }
+
+    for (entity, mut pointers) in &mut entity_pointers {
+        let mut new = new_pointers.remove(&entity).unwrap_or_default();
+        new.sort_by_key(|(_, hit)| FloatOrd(hit.depth));
+        if pointers.pointers != new {
+            pointers.pointers = new;
+        }
+    }
+}
+
+/// A summary of the pointers over a window. See [`WindowInteractions`].
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+pub struct WindowInteraction {
+    /// The pointers located in this window.
+    pub pointers: Vec<PointerId>,
+    /// Whether any pointer in this window is hovering an entity. The window entity itself, which
+    /// can be hovered when window picking is enabled in the
+    /// [`PickingPluginsSettings`](crate::PickingPluginsSettings), does not count as content.
+    pub is_over_content: bool,
+}
+
+/// Maps window entities to a [`WindowInteraction`] summary of the pointers over them. Windows
+/// without any pointers are not in the map.
+///
+/// This is useful in multi-window apps, for example to only enable a tool palette while the user
+/// is working in its window.
+#[derive(Debug, Deref, DerefMut, Default, Resource)]
+pub struct WindowInteractions(pub HashMap<Entity, WindowInteraction>);
+
+impl WindowInteractions {
+    /// Returns `true` if any pointer is located in the `window`.
+    pub fn is_pointer_over(&self, window: Entity) -> bool {
+        self.contains_key(&window)
+    }
+
+    /// Returns `true` if any pointer in the `window` is hovering an entity.
+    pub fn is_over_content(&self, window: Entity) -> bool {
+        self.get(&window)
+            .is_some_and(|interaction| interaction.is_over_content)
+    }
+}
+
+/// Updates the [`WindowInteractions`] from pointer locations and the [`HoverMap`].
+pub fn update_window_interactions(
+    hover_map: Res<HoverMap>,
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    mut window_interactions: ResMut<WindowInteractions>,
+) {
+    let mut new_interactions = HashMap::<Entity, WindowInteraction>::new();
+    for (pointer_id, location) in &pointers {
+        let Some(NormalizedRenderTarget::Window(window)) =
+            location.location().map(|location| &location.target)
+        else {
+            continue;
+        };
+        let window = window.entity();
+        let is_over_content = hover_map
+            .get(pointer_id)
+            .is_some_and(|hovered| hovered.keys().any(|entity| *entity != window));
+        let interaction = new_interactions.entry(window).or_default();
+        interaction.pointers.push(*pointer_id);
+        interaction.is_over_content |= is_over_content;
+    }
+    if window_interactions.0 != new_interactions {
+        window_interactions.0 = new_interactions;
+    }
+}
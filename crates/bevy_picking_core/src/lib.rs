@@ -24,6 +24,11 @@ pub struct PickingPluginsSettings {
     pub is_input_enabled: bool,
     /// Enables and disables updating interaction states of entities.
     pub is_focus_enabled: bool,
+    /// Enables and disables [`window_picking`](backend::window::window_picking), which reports
+    /// the window under each pointer as a hit behind everything else, so windows receive
+    /// [`Pointer`](events::Pointer) events like [`Over`](events::Over) and
+    /// [`Out`](events::Out) when no blocking entity is hovered. Disabled by default.
+    pub is_window_picking_enabled: bool,
 }
 
 impl PickingPluginsSettings {
@@ -36,6 +41,10 @@ impl PickingPluginsSettings {
     pub fn focus_should_run(state: Res<Self>) -> bool {
         state.is_focus_enabled && state.is_enabled
     }
+    /// Whether or not the window picking backend should be running.
+    pub fn window_picking_should_run(state: Res<Self>) -> bool {
+        state.is_window_picking_enabled && state.is_enabled
+    }
 }
 
 impl Default for PickingPluginsSettings {
@@ -44,6 +53,7 @@ impl Default for PickingPluginsSettings {
             is_enabled: true,
             is_input_enabled: true,
             is_focus_enabled: true,
+            is_window_picking_enabled: false,
         }
     }
 }
@@ -104,6 +114,137 @@ impl Pickable {
     };
 }
 
+/// Makes this entity, and all of its descendants, behave as if they had [`Pickable::IGNORE`]. Hits
+/// reported by backends on any of these entities are discarded by the focus system.
+///
+/// This is useful for decorative hierarchies, like particle effects or debug visuals, that are
+/// parented under pickable entities.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
+pub struct IgnoreSubtree;
+
+/// Breaks ties between entities hit at exactly the same depth, in the same layer. Entities with a
+/// higher priority are considered to be above entities with a lower priority. Entities without
+/// this component have a priority of `0`.
+///
+/// This gives explicit control over which entity is hovered when entities are co-located, e.g.
+/// overlapping UI nodes or decals, instead of relying on the order hits are reported in.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[reflect(Component, Default)]
+pub struct PickPriority(pub i32);
+
+/// Restricts which kinds of pointers can pick this entity. Entities without this component can be
+/// picked by any pointer.
+///
+/// Pointers that are not allowed ignore the entity entirely: it is not hovered, does not receive
+/// [`Pointer`](events::Pointer) events, and does not block the entities behind it. This is useful
+/// on hybrid devices, for example to keep on-screen touch controls from being hovered by the mouse.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct PointerFilter {
+    /// The kinds of pointers that can pick this entity.
+    pub allow: PointerKindMask,
+}
+
+impl PointerFilter {
+    /// Only allow the pointers in `allow` to pick this entity.
+    pub fn only(allow: PointerKindMask) -> Self {
+        Self { allow }
+    }
+
+    /// Returns `true` if the pointer with this [`PointerId`](pointer::PointerId) can pick the
+    /// entity.
+    pub fn allows(&self, pointer_id: &pointer::PointerId) -> bool {
+        self.allow.contains(pointer_id)
+    }
+}
+
+impl Default for PointerFilter {
+    fn default() -> Self {
+        Self::only(PointerKindMask::ALL)
+    }
+}
+
+/// A set of pointer kinds, used by [`PointerFilter`]. Combine kinds with `|`, for example
+/// `PointerKindMask::MOUSE | PointerKindMask::CUSTOM`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default)]
+pub struct PointerKindMask(u8);
+
+impl PointerKindMask {
+    /// No pointers.
+    pub const NONE: Self = Self(0);
+    /// The mouse pointer.
+    pub const MOUSE: Self = Self(1);
+    /// Touch pointers.
+    pub const TOUCH: Self = Self(1 << 1);
+    /// Custom pointers, like virtual or gamepad pointers.
+    pub const CUSTOM: Self = Self(1 << 2);
+    /// All pointers.
+    pub const ALL: Self = Self(Self::MOUSE.0 | Self::TOUCH.0 | Self::CUSTOM.0);
+
+    /// The kind of the pointer with this [`PointerId`](pointer::PointerId).
+    pub fn of(pointer_id: &pointer::PointerId) -> Self {
+        match pointer_id {
+            pointer::PointerId::Mouse => Self::MOUSE,
+            pointer::PointerId::Touch(_) => Self::TOUCH,
+            pointer::PointerId::Custom(_) => Self::CUSTOM,
+        }
+    }
+
+    /// Returns `true` if the kind of the pointer with this [`PointerId`](pointer::PointerId) is
+    /// in this set.
+    pub fn contains(&self, pointer_id: &pointer::PointerId) -> bool {
+        self.0 & Self::of(pointer_id).0 != 0
+    }
+}
+
+impl std::ops::BitOr for PointerKindMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Expands the area in which backends report hits on this entity, making small or thin entities
+/// easier to pick, especially with touch inputs.
+///
+/// This only affects whether an entity is hit, not the depth of the hit, so expanded entities are
+/// still sorted by their true depth. Support for this component is backend-specific; it is
+/// supported by the sprite and `bevy_ui` backends.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct PickTolerance {
+    /// The distance, in logical pixels on screen, by which the hit area is expanded.
+    pub screen_radius_px: f32,
+}
+
+impl PickTolerance {
+    /// Expand the hit area of this entity by `screen_radius_px` logical pixels.
+    pub fn new(screen_radius_px: f32) -> Self {
+        Self { screen_radius_px }
+    }
+}
+
+/// Settings to make small entities easier to pick with touch inputs, without resizing them.
+///
+/// When a touch pointer is not directly over any entity, backends that support this setting report
+/// the entity within [`hit_radius_px`](Self::hit_radius_px) of the touch whose center is nearest
+/// to it. Entities under the touch always take precedence, so only the exact miss of a small target
+/// is affected. Mouse and custom pointers are unaffected. Supported by the sprite and `bevy_ui`
+/// backends.
+///
+/// The `bevy_ui` backend only considers interactive nodes, with an `Interaction` or a hoverable
+/// [`Pickable`] component, so container nodes under the touch don't hide nearby buttons.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct TouchPickSettings {
+    /// The radius, in logical pixels on screen, around a touch in which entities can be picked.
+    /// Disabled when zero, which is the default.
+    pub hit_radius_px: f32,
+}
+
 impl Default for Pickable {
     fn default() -> Self {
         Self {
@@ -127,6 +268,8 @@ pub struct PointerCoreBundle {
     pub location: pointer::PointerLocation,
     /// Tracks the pointer's button press state.
     pub click: pointer::PointerPress,
+    /// Tracks how the pointer moved this frame.
+    pub motion: pointer::PointerMotion,
     /// The interaction state of any hovered entities.
     pub interaction: pointer::PointerInteraction,
 }
@@ -146,6 +289,7 @@ impl PointerCoreBundle {
             id,
             location: pointer::PointerLocation::default(),
             click: pointer::PointerPress::default(),
+            motion: pointer::PointerMotion::default(),
             interaction: pointer::PointerInteraction::default(),
         }
     }
@@ -161,7 +305,8 @@ pub enum PickSet {
     PostInput,
     /// Receives and processes pointer input events. In the [`PreUpdate`] schedule.
     ProcessInput,
-    /// Reads inputs and produces [`backend::PointerHits`]s. In the [`PreUpdate`] schedule.
+    /// Reads inputs and produces [`backend::PointerHits`]s. In the [`PreUpdate`] schedule. Only
+    /// runs if [`pointer::any_active_pointer`].
     Backend,
     /// Reads [`backend::PointerHits`]s, and updates focus, selection, and highlighting states. In
     /// the [`PreUpdate`] schedule.
@@ -173,12 +318,39 @@ pub enum PickSet {
     Last,
 }
 
+/// Orders backends within [`PickSet::Backend`], for backends that depend on the output of others.
+///
+/// Most backends only read [`PointerLocation`](pointer::PointerLocation)s or the
+/// [`RayMap`](backend::ray::RayMap), and should add their systems to [`BackendSet::Primary`]. A
+/// backend that needs to read or adjust the [`PointerHits`](backend::PointerHits) of other
+/// backends, for example to composite them, should add its systems to [`BackendSet::PostProcess`],
+/// which runs after all primary backends.
+///
+/// ```ignore
+/// app.add_systems(PreUpdate, my_backend.in_set(BackendSet::Primary));
+/// app.add_systems(PreUpdate, my_compositing_backend.in_set(BackendSet::PostProcess));
+/// ```
+///
+/// Both sets are part of [`PickSet::Backend`], so systems that are only added to
+/// [`PickSet::Backend`] are not ordered relative to either of them.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum BackendSet {
+    /// Backends that produce [`PointerHits`](backend::PointerHits) from pointer locations. In the
+    /// [`PreUpdate`] schedule.
+    Primary,
+    /// Backends that read the [`PointerHits`](backend::PointerHits) sent by [`BackendSet::Primary`]
+    /// backends. In the [`PreUpdate`] schedule.
+    PostProcess,
+}
+
 /// Receives input events, and provides the shared types used by other picking plugins.
 pub struct CorePlugin;
 impl Plugin for CorePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PickingPluginsSettings>()
+            .init_resource::<TouchPickSettings>()
             .init_resource::<pointer::PointerMap>()
+            .init_resource::<pointer::ProcessedMoves>()
             .init_resource::<backend::ray::RayMap>()
             .add_event::<pointer::InputPress>()
             .add_event::<pointer::InputMove>()
@@ -189,16 +361,24 @@ impl Plugin for CorePlugin {
                     pointer::update_pointer_map,
                     pointer::InputMove::receive,
                     pointer::InputPress::receive,
+                    pointer::PointerInputBuffer::receive
+                        .run_if(resource_exists::<pointer::PointerInputBuffer>),
                     backend::ray::RayMap::repopulate,
                 )
                     .in_set(PickSet::ProcessInput),
             )
+            .add_systems(
+                PreUpdate,
+                backend::window::window_picking
+                    .in_set(BackendSet::Primary)
+                    .run_if(PickingPluginsSettings::window_picking_should_run),
+            )
             .configure_sets(First, (PickSet::Input, PickSet::PostInput).chain())
             .configure_sets(
                 PreUpdate,
                 (
                     PickSet::ProcessInput.run_if(PickingPluginsSettings::input_should_run),
-                    PickSet::Backend,
+                    PickSet::Backend.run_if(pointer::any_active_pointer),
                     PickSet::Focus.run_if(PickingPluginsSettings::focus_should_run),
                     PickSet::PostFocus,
                     EventListenerSet,
@@ -206,13 +386,28 @@ impl Plugin for CorePlugin {
                 )
                     .chain(),
             )
+            .configure_sets(
+                PreUpdate,
+                (BackendSet::Primary, BackendSet::PostProcess)
+                    .chain()
+                    .in_set(PickSet::Backend),
+            )
             .register_type::<pointer::PointerId>()
             .register_type::<pointer::PointerLocation>()
+            .register_type::<pointer::PointerConfine>()
             .register_type::<pointer::PointerPress>()
+            .register_type::<pointer::PointerMotion>()
             .register_type::<pointer::PointerInteraction>()
             .register_type::<Pickable>()
+            .register_type::<IgnoreSubtree>()
+            .register_type::<PickPriority>()
+            .register_type::<PickTolerance>()
+            .register_type::<PointerFilter>()
+            .register_type::<PointerKindMask>()
+            .register_type::<TouchPickSettings>()
             .register_type::<PickingPluginsSettings>()
-            .register_type::<backend::ray::RayId>();
+            .register_type::<backend::ray::RayId>()
+            .register_type::<backend::HitData>();
     }
 }
 
@@ -221,20 +416,58 @@ pub struct InteractionPlugin;
 impl Plugin for InteractionPlugin {
     fn build(&self, app: &mut App) {
         use events::*;
-        use focus::{update_focus, update_interactions};
+        use focus::{
+            apply_hover_delay, propagate_interactions, update_entity_pointers, update_focus,
+            update_interactions, update_modal_stack, update_window_interactions,
+        };
 
         app.init_resource::<focus::HoverMap>()
             .init_resource::<focus::PreviousHoverMap>()
+            .init_resource::<focus::PointerOverMap>()
+            .init_resource::<focus::WindowInteractions>()
             .init_resource::<DragMap>()
+            .init_resource::<DragOverMap>()
+            .init_resource::<GestureMap>()
+            .init_resource::<focus::GlobalPickState>()
+            .init_resource::<focus::ModalStack>()
+            .init_resource::<focus::HoverDelay>()
+            .init_resource::<HoldRepeatSettings>()
+            .init_resource::<ClickSettings>()
+            .init_resource::<ContextMenuSettings>()
             .add_event::<PointerCancel>()
+            .add_event::<GestureTransition>()
+            .add_event::<focus::InteractionChanged>()
+            .register_type::<focus::EntityPointers>()
+            .register_type::<focus::GlobalPickState>()
+            .register_type::<focus::HoverDelay>()
+            .register_type::<focus::InteractionChanged>()
+            .register_type::<focus::PickingInteraction>()
+            .register_type::<focus::PickingModal>()
+            .register_type::<focus::PropagateInteraction>()
+            .register_type::<focus::WindowInteraction>()
+            .register_type::<DragConstraint>()
+            .register_type::<ClampDragToEntity>()
+            .register_type::<DragBounds>()
+            .register_type::<GestureState>()
+            .register_type::<GestureTransition>()
+            .register_type::<HoldRepeatSettings>()
+            .register_type::<ClickSettings>()
+            .register_type::<ContextMenuSettings>()
             .add_systems(
                 PreUpdate,
                 (
+                    update_modal_stack,
                     update_focus,
+                    apply_hover_delay,
                     pointer_events,
                     update_interactions,
+                    propagate_interactions,
+                    update_entity_pointers,
+                    update_window_interactions,
                     send_click_and_drag_events,
+                    send_context_menu_events,
                     send_drag_over_events,
+                    send_hold_repeat_events,
                 )
                     .chain()
                     .in_set(PickSet::Focus),
@@ -253,6 +486,8 @@ impl Plugin for InteractionPlugin {
                 EventListenerPlugin::<Pointer<DragOver>>::default(),
                 EventListenerPlugin::<Pointer<DragLeave>>::default(),
                 EventListenerPlugin::<Pointer<Drop>>::default(),
+                EventListenerPlugin::<Pointer<HoldRepeat>>::default(),
+                EventListenerPlugin::<Pointer<ContextMenu>>::default(),
             ));
     }
 }
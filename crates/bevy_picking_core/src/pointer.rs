@@ -5,7 +5,7 @@ use bevy_math::{Rect, Vec2};
 use bevy_reflect::prelude::*;
 use bevy_render::camera::{Camera, NormalizedRenderTarget};
 use bevy_utils::HashMap;
-use bevy_window::PrimaryWindow;
+use bevy_window::{PrimaryWindow, Window};
 
 pub use uuid::Uuid;
 
@@ -87,6 +87,18 @@ impl PointerMap {
     pub fn get_entity(&self, pointer_id: PointerId) -> Option<Entity> {
         self.inner.get(&pointer_id).copied()
     }
+
+    /// Get the [`PointerId`] of the supplied pointer [`Entity`].
+    pub fn get_id(&self, entity: Entity) -> Option<PointerId> {
+        self.inner
+            .iter()
+            .find_map(|(id, pointer_entity)| (*pointer_entity == entity).then_some(*id))
+    }
+
+    /// Iterates over all pointers and their entities.
+    pub fn iter(&self) -> impl Iterator<Item = (PointerId, Entity)> + '_ {
+        self.inner.iter().map(|(id, entity)| (*id, *entity))
+    }
 }
 
 /// Update the [`PointerMap`] resource with the current frame's data.
@@ -98,7 +110,7 @@ pub fn update_pointer_map(pointers: Query<(Entity, &PointerId)>, mut map: ResMut
 }
 
 /// Tracks the state of the pointer's buttons in response to [`InputPress`]s.
-#[derive(Debug, Default, Clone, Component, Reflect, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, Component, Reflect, PartialEq, Eq)]
 #[reflect(Component, Default)]
 pub struct PointerPress {
     primary: bool,
@@ -129,6 +141,34 @@ impl PointerPress {
     pub fn is_any_pressed(&self) -> bool {
         self.primary || self.middle || self.secondary
     }
+
+    /// Returns true if the `button` is pressed.
+    #[inline]
+    pub fn is_pressed(&self, button: PointerButton) -> bool {
+        match button {
+            PointerButton::Primary => self.primary,
+            PointerButton::Secondary => self.secondary,
+            PointerButton::Middle => self.middle,
+        }
+    }
+
+    /// Returns the number of pointer buttons that are pressed.
+    #[inline]
+    pub fn pressed_count(&self) -> usize {
+        PointerButton::iter()
+            .filter(|button| self.is_pressed(*button))
+            .count()
+    }
+
+    /// Updates the state of the button changed by the `press`.
+    pub(crate) fn apply(&mut self, press: &InputPress) {
+        let is_down = press.direction == PressDirection::Down;
+        match press.button {
+            PointerButton::Primary => self.primary = is_down,
+            PointerButton::Secondary => self.secondary = is_down,
+            PointerButton::Middle => self.middle = is_down,
+        }
+    }
 }
 
 /// Pointer input event for button presses. Fires when a pointer button changes state.
@@ -180,12 +220,7 @@ impl InputPress {
         for input_press_event in events.read() {
             pointers.iter_mut().for_each(|(pointer_id, mut pointer)| {
                 if *pointer_id == input_press_event.pointer_id {
-                    let is_down = input_press_event.direction == PressDirection::Down;
-                    match input_press_event.button {
-                        PointerButton::Primary => pointer.primary = is_down,
-                        PointerButton::Secondary => pointer.secondary = is_down,
-                        PointerButton::Middle => pointer.middle = is_down,
-                    }
+                    pointer.apply(input_press_event);
                 }
             })
         }
@@ -236,6 +271,48 @@ impl PointerLocation {
     }
 }
 
+/// A run condition that returns `true` if any pointer has a [`Location`].
+///
+/// [`PickSet::Backend`](crate::PickSet::Backend) only runs if this is `true`, so backends are
+/// skipped when no pointer can hit anything, for example when the only pointer is the mouse and it
+/// has left the window. Backends that run outside of that set can use this condition to do the
+/// same.
+pub fn any_active_pointer(pointers: Query<&PointerLocation>) -> bool {
+    pointers.iter().any(|pointer| pointer.location.is_some())
+}
+
+/// Tracks how a pointer moved this frame, updated by [`InputMove::receive`] every frame, including
+/// frames without any [`InputMove`] events. Use this to compute pointer velocity without reusing
+/// the delta of the last move when the pointer is stationary.
+#[derive(Debug, Default, Clone, Copy, Component, Reflect, PartialEq)]
+#[reflect(Component, Default)]
+pub struct PointerMotion {
+    moved_this_frame: bool,
+    previous_position: Option<Vec2>,
+    delta: Vec2,
+}
+impl PointerMotion {
+    /// Returns true if the pointer received any [`InputMove`] this frame.
+    #[inline]
+    pub fn moved_this_frame(&self) -> bool {
+        self.moved_this_frame
+    }
+
+    /// The position of the pointer at the end of the previous frame, or `None` if the pointer did
+    /// not have a location.
+    #[inline]
+    pub fn previous_position(&self) -> Option<Vec2> {
+        self.previous_position
+    }
+
+    /// The sum of the deltas of the [`InputMove`]s received this frame. This is zero if the pointer
+    /// did not move.
+    #[inline]
+    pub fn delta(&self) -> Vec2 {
+        self.delta
+    }
+}
+
 /// Pointer input event for pointer moves. Fires when a pointer changes location.
 #[derive(Event, Debug, Clone, Reflect)]
 pub struct InputMove {
@@ -256,21 +333,149 @@ impl InputMove {
         }
     }
 
-    /// Receives [`InputMove`] events and updates corresponding [`PointerLocation`] components.
+    /// Receives [`InputMove`] events and updates corresponding [`PointerLocation`] and
+    /// [`PointerMotion`] components. If the pointer has a [`PointerConfine`] component, the
+    /// position is clamped to its bounds. The moves are stored in [`ProcessedMoves`].
     pub fn receive(
         mut events: EventReader<InputMove>,
-        mut pointers: Query<(&PointerId, &mut PointerLocation)>,
+        mut processed: ResMut<ProcessedMoves>,
+        mut pointers: Query<(
+            &PointerId,
+            &mut PointerLocation,
+            Option<&PointerConfine>,
+            Option<&mut PointerMotion>,
+        )>,
     ) {
+        processed.0.clear();
+
+        // Start the frame stationary, at the position the last frame ended at.
+        for (_, pointer, _, motion) in &mut pointers {
+            if let Some(mut motion) = motion {
+                motion.set_if_neq(PointerMotion {
+                    moved_this_frame: false,
+                    previous_position: pointer.location().map(|location| location.position),
+                    delta: Vec2::ZERO,
+                });
+            }
+        }
+
         for event_pointer in events.read() {
-            pointers.iter_mut().for_each(|(id, mut pointer)| {
-                if *id == event_pointer.pointer_id {
-                    pointer.location = Some(event_pointer.location.to_owned());
+            for (id, mut pointer, confine, motion) in &mut pointers {
+                if *id != event_pointer.pointer_id {
+                    continue;
                 }
-            })
+                let mut input_move = event_pointer.clone();
+                if let Some(confine) = confine {
+                    let position = confine.clamp(input_move.location.position);
+                    // Only the distance moved inside the bounds counts.
+                    if let Some(previous) = pointer
+                        .location()
+                        .filter(|location| location.target == input_move.location.target)
+                    {
+                        input_move.delta = position - previous.position;
+                    }
+                    input_move.location.position = position;
+                }
+                pointer.location = Some(input_move.location.clone());
+                if let Some(mut motion) = motion {
+                    motion.moved_this_frame = true;
+                    motion.delta += input_move.delta;
+                }
+                processed.0.push(input_move);
+            }
         }
     }
 }
 
+/// The [`InputMove`]s processed by [`InputMove::receive`] this frame, in the order they were
+/// received. Positions are clamped to the [`PointerConfine`] of their pointer, and deltas are the
+/// distance moved after clamping, so they always match the [`PointerLocation`]. Pointer events are
+/// built from these moves, instead of the raw [`InputMove`] events.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ProcessedMoves(Vec<InputMove>);
+
+impl Deref for ProcessedMoves {
+    type Target = [InputMove];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Buffers [`InputPress`] and [`InputMove`] events, so they can be consumed in a schedule that does
+/// not run every frame, like `FixedUpdate`.
+///
+/// Events are only kept for two frames, so a fixed timestep system reading them directly can miss
+/// or duplicate inputs. Insert this resource to opt in to buffering: every input received by the
+/// picking plugin is appended, in order, until it is drained. For deterministic or networked
+/// simulations, drain the buffer once per tick to process pointer inputs aligned with that tick.
+///
+/// Hovering and pointer events are still computed every frame in the `PreUpdate` schedule.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PointerInputBuffer {
+    presses: Vec<InputPress>,
+    moves: Vec<InputMove>,
+}
+
+impl PointerInputBuffer {
+    /// Removes and returns all buffered [`InputPress`] events, oldest first.
+    pub fn drain_presses(&mut self) -> impl Iterator<Item = InputPress> + '_ {
+        self.presses.drain(..)
+    }
+
+    /// Removes and returns all buffered [`InputMove`] events, oldest first.
+    pub fn drain_moves(&mut self) -> impl Iterator<Item = InputMove> + '_ {
+        self.moves.drain(..)
+    }
+
+    /// Appends the input events received this frame to the buffer. Only runs if the resource
+    /// exists.
+    pub fn receive(
+        mut buffer: ResMut<Self>,
+        mut presses: EventReader<InputPress>,
+        mut moves: EventReader<InputMove>,
+    ) {
+        buffer.presses.extend(presses.read().copied());
+        buffer.moves.extend(moves.read().cloned());
+    }
+}
+
+/// Confines the [`PointerLocation`] of this pointer to a region of its render target. Positions
+/// received from [`InputMove`] events are clamped to the `bounds` before being stored, and before
+/// pointer events are sent from them, see [`ProcessedMoves`]. This is useful to keep virtual
+/// pointers, like those driven by a gamepad, inside a viewport or UI area.
+///
+/// Pointers without this component are unconfined.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct PointerConfine {
+    /// The region the pointer is confined to, in logical pixels of the render target.
+    pub bounds: Rect,
+}
+
+impl PointerConfine {
+    /// Confine a pointer to the supplied `bounds`.
+    pub fn new(bounds: Rect) -> Self {
+        Self { bounds }
+    }
+
+    /// Confine a pointer to the viewport of this [`Camera`]. Returns `None` if the size of the
+    /// viewport is not yet known.
+    pub fn to_viewport(camera: &Camera) -> Option<Self> {
+        camera.logical_viewport_rect().map(Self::new)
+    }
+
+    /// Confine a pointer to the bounds of this [`Window`].
+    pub fn to_window(window: &Window) -> Self {
+        Self::new(Rect::new(0.0, 0.0, window.width(), window.height()))
+    }
+
+    /// Clamp a position to the bounds of this confinement.
+    pub fn clamp(&self, position: Vec2) -> Vec2 {
+        position.clamp(self.bounds.min, self.bounds.max)
+    }
+}
+
 /// The location of a pointer, including the current [`NormalizedRenderTarget`], and the x/y
 /// position of the pointer on this render target.
 ///
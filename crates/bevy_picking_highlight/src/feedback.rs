@@ -0,0 +1,82 @@
+//! Plays sounds in response to changes in an entity's [`PickingInteraction`].
+//!
+//! This covers the extremely common case of "play a sound when a button is hovered or clicked",
+//! without needing to write an `On<Pointer<E>>` callback for every entity.
+
+use bevy_app::prelude::*;
+use bevy_asset::prelude::*;
+use bevy_audio::{AudioBundle, AudioSource, PlaybackSettings};
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use bevy_utils::HashMap;
+
+use bevy_picking_core::{focus::PickingInteraction, PickSet};
+
+use crate::HighlightPluginSettings;
+
+/// Adds support for the [`InteractionFeedback`] component. This is added automatically by the
+/// [`DefaultHighlightingPlugin`](crate::DefaultHighlightingPlugin).
+pub struct InteractionFeedbackPlugin;
+impl Plugin for InteractionFeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            play_interaction_feedback
+                .in_set(PickSet::Last)
+                .run_if(HighlightPluginSettings::should_run),
+        )
+        .register_type::<InteractionFeedback>();
+    }
+}
+
+/// Plays a sound when the [`PickingInteraction`] of this entity changes.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct InteractionFeedback {
+    /// Played when the entity goes from not being interacted with to being hovered.
+    pub hover: Option<Handle<AudioSource>>,
+    /// Played when the entity is pressed.
+    pub click: Option<Handle<AudioSource>>,
+}
+
+/// Spawns a one-shot audio entity when an entity with [`InteractionFeedback`] transitions to a new
+/// [`PickingInteraction`] state.
+pub fn play_interaction_feedback(
+    mut commands: Commands,
+    mut previous: Local<HashMap<Entity, PickingInteraction>>,
+    mut removed: RemovedComponents<InteractionFeedback>,
+    feedback: Query<
+        (Entity, &PickingInteraction, &InteractionFeedback),
+        Changed<PickingInteraction>,
+    >,
+) {
+    for entity in removed.read() {
+        previous.remove(&entity);
+    }
+
+    for (entity, interaction, feedback) in &feedback {
+        // `PickingInteraction` is reset and re-written every frame, so we need to compare against
+        // the last state we saw to find actual transitions.
+        let last = previous.get(&entity).copied().unwrap_or_default();
+        if last == *interaction {
+            continue;
+        }
+
+        let sound = match (last, interaction) {
+            (PickingInteraction::None, PickingInteraction::Hovered) => feedback.hover.as_ref(),
+            (_, PickingInteraction::Pressed) => feedback.click.as_ref(),
+            _ => None,
+        };
+        if let Some(source) = sound {
+            commands.spawn(AudioBundle {
+                source: source.clone(),
+                settings: PlaybackSettings::DESPAWN,
+            });
+        }
+
+        match interaction {
+            PickingInteraction::None => previous.remove(&entity),
+            _ => previous.insert(entity, *interaction),
+        };
+    }
+}
@@ -5,13 +5,25 @@
 #![allow(clippy::too_many_arguments)]
 #![deny(missing_docs)]
 
+#[cfg(feature = "audio")]
+pub mod feedback;
+
+use std::time::Duration;
+
 #[allow(unused_imports)]
 use bevy_app::prelude::*;
 use bevy_asset::{prelude::*, Asset};
 use bevy_ecs::prelude::*;
 use bevy_reflect::prelude::*;
-
-use bevy_picking_core::{focus::PickingInteraction, PickSet, PickingPluginsSettings};
+use bevy_time::Time;
+use bevy_utils::{HashMap, HashSet};
+
+use bevy_picking_core::{
+    events::{Click, DragEnd, DragStart, Pointer, PointerCancel},
+    focus::PickingInteraction,
+    pointer::PointerId,
+    PickSet, PickingPluginsSettings,
+};
 #[cfg(feature = "selection")]
 use bevy_picking_selection::PickSelection;
 
@@ -21,6 +33,9 @@ pub mod prelude {
         DefaultHighlightingPlugin, GlobalHighlight, Highlight, HighlightKind, HighlightPlugin,
         HighlightPluginSettings, PickHighlight,
     };
+
+    #[cfg(feature = "audio")]
+    pub use crate::feedback::{InteractionFeedback, InteractionFeedbackPlugin};
 }
 
 /// A resource used to enable or disable picking highlighting.
@@ -29,6 +44,11 @@ pub mod prelude {
 pub struct HighlightPluginSettings {
     /// Should highlighting systems run?
     pub is_enabled: bool,
+    /// How long to show the pressed highlight after an entity is clicked. A press and release can
+    /// happen within a single frame, in which case the pressed highlight is never shown, so this
+    /// makes sure fast taps still produce visible feedback. Disabled when zero, which is the
+    /// default.
+    pub click_flash: Duration,
 }
 
 impl HighlightPluginSettings {
@@ -40,7 +60,10 @@ impl HighlightPluginSettings {
 
 impl Default for HighlightPluginSettings {
     fn default() -> Self {
-        Self { is_enabled: true }
+        Self {
+            is_enabled: true,
+            click_flash: Duration::ZERO,
+        }
     }
 }
 
@@ -59,11 +82,28 @@ pub struct PickHighlight;
 /// For example, to update the `StandardMaterial` highlight color for 3D meshes, you would access
 /// `ResMut<GlobalHighlight<StandardMaterial>>`.
 ///
+/// To use different highlight assets from the start, insert a [`GlobalHighlight<T>`] resource
+/// before the `Startup` schedule, for example in a `PreStartup` system. The defaults are only
+/// generated if the resource is missing.
+///
 /// ### Overriding Highlighting Appearance
 ///
 /// By default, this plugin will use the  resource to define global highlighting settings for assets
 /// of type `T`. You can override this global default with the optional fields in the [`Highlight`]
 /// component.
+///
+/// ### Highlighting Multiple Assets
+///
+/// An entity can be highlighted with more than one asset type, for example to swap both its
+/// material and its mesh when hovered. Add a [`HighlightPlugin<T>`] for each asset type: every
+/// plugin tracks its own [`InitialHighlight<T>`], [`GlobalHighlight<T>`] and [`Highlight<T>`], so
+/// they don't interfere with each other, and all of them follow the same interaction state. An
+/// asset handle added after the [`PickHighlight`] component is picked up as soon as it is added.
+///
+/// ### Interaction Feedback
+///
+/// With the `audio` feature enabled, this also adds the `feedback::InteractionFeedbackPlugin`,
+/// which plays sounds on entities with a `feedback::InteractionFeedback` component.
 pub struct DefaultHighlightingPlugin;
 impl Plugin for DefaultHighlightingPlugin {
     #[allow(unused_variables)]
@@ -72,6 +112,9 @@ impl Plugin for DefaultHighlightingPlugin {
             .register_type::<PickHighlight>()
             .register_type::<HighlightPluginSettings>();
 
+        #[cfg(feature = "audio")]
+        app.add_plugins(feedback::InteractionFeedbackPlugin);
+
         #[cfg(feature = "pbr")]
         app.add_plugins(HighlightPlugin::<bevy_pbr::StandardMaterial> {
             highlighting_default: |mut assets| GlobalHighlight {
@@ -79,6 +122,7 @@ impl Plugin for DefaultHighlightingPlugin {
                 pressed: assets.add(bevy_color::Color::srgb(0.35, 0.75, 0.35)),
                 #[cfg(feature = "selection")]
                 selected: assets.add(bevy_color::Color::srgb(0.35, 0.35, 0.75)),
+                dragging: None,
             },
         });
 
@@ -89,6 +133,7 @@ impl Plugin for DefaultHighlightingPlugin {
                 pressed: assets.add(bevy_color::Color::srgb(0.35, 0.75, 0.35)),
                 #[cfg(feature = "selection")]
                 selected: assets.add(bevy_color::Color::srgb(0.35, 0.35, 0.75)),
+                dragging: None,
             },
         });
     }
@@ -98,7 +143,7 @@ impl Plugin for DefaultHighlightingPlugin {
 /// highlighting states.
 pub struct HighlightPlugin<T: 'static + Asset + Sync + Send> {
     /// A function that is invoked at startup to allow you to generate the default highlighting
-    /// states for `T`.
+    /// states for `T`. This is skipped if a [`GlobalHighlight<T>`] resource already exists.
     pub highlighting_default: fn(ResMut<Assets<T>>) -> GlobalHighlight<T>,
 }
 
@@ -109,10 +154,18 @@ where
     fn build(&self, app: &mut App) {
         let highlighting_default = self.highlighting_default;
 
+        // Shared by every highlight plugin, so this plugin also works without the default one.
+        app.init_resource::<HighlightPluginSettings>();
+
         app.add_systems(
             Startup,
-            move |mut commands: Commands, assets: ResMut<Assets<T>>| {
-                commands.insert_resource(highlighting_default(assets));
+            move |mut commands: Commands,
+                  assets: ResMut<Assets<T>>,
+                  existing: Option<Res<GlobalHighlight<T>>>| {
+                // Keep the highlight assets the user has already inserted.
+                if existing.is_none() {
+                    commands.insert_resource(highlighting_default(assets));
+                }
             },
         )
         .add_systems(
@@ -120,9 +173,12 @@ where
             (
                 get_initial_highlight_asset::<T>,
                 Highlight::<T>::update_dynamic,
+                refresh_highlight_assets::<T>,
                 update_highlight_assets::<T>,
                 #[cfg(feature = "selection")]
                 update_selection::<T>,
+                update_click_flash::<T>,
+                update_drag_highlight::<T>,
             )
                 .chain()
                 .in_set(PickSet::Last)
@@ -137,6 +193,7 @@ where
 /// Component used to track the initial asset state of a highlightable object. This is needed to
 /// return the highlighting asset back to its original state after highlighting it.
 #[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
 pub struct InitialHighlight<T: Asset> {
     /// A handle for the initial asset state of the highlightable entity.
     pub initial: Handle<T>,
@@ -145,6 +202,7 @@ pub struct InitialHighlight<T: Asset> {
 /// Resource that defines the global default highlighting assets to use. This can be overridden
 /// per-entity with the [`Highlight`] component.
 #[derive(Resource, Reflect)]
+#[reflect(Resource)]
 pub struct GlobalHighlight<T: Asset> {
     /// Default asset handle to use for hovered entities without the [`Highlight`] component.
     pub hovered: Handle<T>,
@@ -153,6 +211,9 @@ pub struct GlobalHighlight<T: Asset> {
     /// Default asset handle to use for selected entities without the [`Highlight`] component.
     #[cfg(feature = "selection")]
     pub selected: Handle<T>,
+    /// Default asset handle to use for dragged entities without the [`Highlight`] component. If
+    /// this is `None`, dragged entities use the pressed or hovered highlight as usual.
+    pub dragging: Option<Handle<T>>,
 }
 
 impl<T: Asset> GlobalHighlight<T> {
@@ -179,6 +240,67 @@ impl<T: Asset> GlobalHighlight<T> {
             .and_then(|h| h.get_handle())
             .unwrap_or_else(|| self.selected.clone())
     }
+
+    /// Returns the dragging highlight override if it exists, falling back to the default, if any.
+    pub fn dragging(&self, h_override: &Option<&Highlight<T>>) -> Option<Handle<T>> {
+        h_override
+            .and_then(|h| h.dragging.as_ref())
+            .and_then(|h| h.get_handle())
+            .or_else(|| self.dragging.clone())
+    }
+
+    /// Returns the highlight asset of an entity in the `interaction` state, falling back to the
+    /// selected highlight for selected entities that are not interacted with, then to the entity's
+    /// `initial` asset. `is_selected` is ignored without the `selection` feature.
+    #[cfg_attr(not(feature = "selection"), allow(unused_variables))]
+    pub fn for_state(
+        &self,
+        interaction: &PickingInteraction,
+        is_selected: bool,
+        initial: &Handle<T>,
+        h_override: &Option<&Highlight<T>>,
+    ) -> Handle<T> {
+        match interaction {
+            PickingInteraction::Pressed => self.pressed(h_override),
+            PickingInteraction::Hovered => self.hovered(h_override),
+            #[cfg(feature = "selection")]
+            PickingInteraction::None if is_selected => self.selected(h_override),
+            PickingInteraction::None => initial.to_owned(),
+        }
+    }
+
+    /// Returns `true` if `handle` is one of the highlight assets that can be applied to an entity
+    /// with these overrides, rather than the entity's own asset.
+    fn is_highlight_asset(&self, handle: &Handle<T>, h_override: &Option<&Highlight<T>>) -> bool {
+        let globals = [
+            Some(&self.hovered),
+            Some(&self.pressed),
+            self.dragging.as_ref(),
+        ];
+        #[cfg(feature = "selection")]
+        let globals = [globals[0], globals[1], globals[2], Some(&self.selected)];
+        if globals.iter().flatten().any(|global| *global == handle) {
+            return true;
+        }
+        let Some(h_override) = h_override else {
+            return false;
+        };
+        let Highlight {
+            hovered,
+            pressed,
+            #[cfg(feature = "selection")]
+            selected,
+            dragging,
+        } = h_override;
+        #[cfg(not(feature = "selection"))]
+        let overrides = [hovered, pressed, dragging];
+        #[cfg(feature = "selection")]
+        let overrides = [hovered, pressed, dragging, selected];
+        overrides
+            .into_iter()
+            .flatten()
+            .any(|kind| kind.get_handle().as_ref() == Some(handle))
+    }
 }
 
 /// Used to override each highlighting state in [`Highlight`].
@@ -191,8 +313,8 @@ pub enum HighlightKind<T: Asset> {
     Dynamic {
         /// The function to set.
         function: fn(initial: &T) -> T,
-        /// The function will be run when the entity's Handle changes, and the output will be stored
-        /// here.
+        /// The function will be run when the entity's Handle or the [`GlobalHighlight`] changes,
+        /// and the output will be stored here.
         cache: Option<Handle<T>>,
     },
 }
@@ -234,8 +356,11 @@ impl<T: Asset> std::fmt::Debug for HighlightKind<T> {
 }
 
 /// Overrides the global highlighting material for an entity. See [`PickHighlight`].
-#[derive(Component, Clone, Debug, Default, Reflect)]
-#[reflect(from_reflect = false)]
+///
+/// The fields of this component are ignored by reflection, because [`HighlightKind`] cannot be
+/// constructed through reflection.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default, from_reflect = false)]
 pub struct Highlight<T: Asset> {
     /// Overrides this asset's global default appearance when hovered
     #[reflect(ignore)]
@@ -247,29 +372,51 @@ pub struct Highlight<T: Asset> {
     #[reflect(ignore)]
     #[cfg(feature = "selection")]
     pub selected: Option<HighlightKind<T>>,
+    /// Overrides this asset's global default appearance when dragged
+    #[reflect(ignore)]
+    pub dragging: Option<HighlightKind<T>>,
+}
+
+impl<T: Asset> Default for Highlight<T> {
+    fn default() -> Self {
+        Self {
+            hovered: None,
+            pressed: None,
+            #[cfg(feature = "selection")]
+            selected: None,
+            dragging: None,
+        }
+    }
 }
 
 impl<T: Asset> Highlight<T> {
-    /// System that updates the dynamic overrides when the entity's Handle changes.
+    /// System that updates the dynamic overrides when the entity's Handle changes, when the
+    /// [`Highlight`] is added, or when the [`GlobalHighlight`] changes.
     fn update_dynamic(
         mut asset_server: ResMut<Assets<T>>,
-        mut entities: Query<
-            (&mut Highlight<T>, &InitialHighlight<T>),
-            Changed<InitialHighlight<T>>,
-        >,
+        global_defaults: Res<GlobalHighlight<T>>,
+        mut entities: Query<(&mut Highlight<T>, Ref<InitialHighlight<T>>)>,
     ) {
         for (mut highlight_override, highlight_initial) in entities.iter_mut() {
+            if !global_defaults.is_changed()
+                && !highlight_initial.is_changed()
+                && !highlight_override.is_added()
+            {
+                continue;
+            }
             let Highlight {
                 hovered,
                 pressed,
                 #[cfg(feature = "selection")]
                 selected,
+                dragging,
             } = highlight_override.as_mut();
 
             let mut h = hovered.as_mut().and_then(|h| h.get_dynamic());
             let mut p = pressed.as_mut().and_then(|h| h.get_dynamic());
+            let mut d = dragging.as_mut().and_then(|h| h.get_dynamic());
 
-            let iter = h.iter_mut().chain(p.iter_mut());
+            let iter = h.iter_mut().chain(p.iter_mut()).chain(d.iter_mut());
 
             #[cfg(feature = "selection")]
             let mut s = selected.as_mut().and_then(|h| h.get_dynamic());
@@ -286,14 +433,30 @@ impl<T: Asset> Highlight<T> {
 }
 
 /// Automatically records the "initial" state of highlightable entities.
+///
+/// This is also updated when the entity's asset is replaced with one that is not a highlight
+/// asset, so that changing an entity's material at runtime changes the asset it returns to.
 pub fn get_initial_highlight_asset<T: Asset>(
     mut commands: Commands,
-    entity_asset_query: Query<(Entity, &Handle<T>), Added<PickHighlight>>,
+    global_defaults: Res<GlobalHighlight<T>>,
+    entity_asset_query: Query<
+        (Entity, &Handle<T>, Option<&Highlight<T>>),
+        (
+            With<PickHighlight>,
+            Or<(Added<PickHighlight>, Changed<Handle<T>>)>,
+        ),
+    >,
     mut highlighting_query: Query<Option<&mut InitialHighlight<T>>>,
 ) {
-    for (entity, material) in entity_asset_query.iter() {
+    for (entity, material, h_override) in entity_asset_query.iter() {
         match highlighting_query.get_mut(entity) {
-            Ok(Some(mut highlighting)) => material.clone_into(&mut highlighting.initial),
+            Ok(Some(mut highlighting)) => {
+                if highlighting.initial != *material
+                    && !global_defaults.is_highlight_asset(material, &h_override)
+                {
+                    material.clone_into(&mut highlighting.initial);
+                }
+            }
             _ => {
                 commands.entity(entity).try_insert(InitialHighlight {
                     initial: material.to_owned(),
@@ -303,6 +466,41 @@ pub fn get_initial_highlight_asset<T: Asset>(
     }
 }
 
+/// Re-applies the highlighting asset of every entity when the [`GlobalHighlight`] changes, and of
+/// entities whose [`Highlight`] changed, so new assets take effect without waiting for the next
+/// interaction.
+#[cfg_attr(not(feature = "selection"), allow(unused_variables))]
+pub fn refresh_highlight_assets<T: Asset>(
+    global_defaults: Res<GlobalHighlight<T>>,
+    mut highlight_query: Query<(
+        Entity,
+        &mut Handle<T>,
+        &PickingInteraction,
+        &InitialHighlight<T>,
+        Option<Ref<Highlight<T>>>,
+    )>,
+    #[cfg(feature = "selection")] selections: Query<&PickSelection>,
+) {
+    for (entity, mut asset, interaction, init_highlight, h_override) in &mut highlight_query {
+        if !global_defaults.is_changed() && !h_override.as_ref().is_some_and(|h| h.is_changed()) {
+            continue;
+        }
+        let h_override = h_override.as_deref();
+        #[cfg(feature = "selection")]
+        let is_selected = selections
+            .get(entity)
+            .is_ok_and(|selection| selection.is_selected);
+        #[cfg(not(feature = "selection"))]
+        let is_selected = false;
+        asset.set_if_neq(global_defaults.for_state(
+            interaction,
+            is_selected,
+            &init_highlight.initial,
+            &h_override,
+        ));
+    }
+}
+
 /// Apply highlighting assets to entities based on their state.
 pub fn update_highlight_assets<T: Asset>(
     global_defaults: Res<GlobalHighlight<T>>,
@@ -350,3 +548,129 @@ pub fn update_selection<T: Asset>(
         }
     }
 }
+
+/// Shows the pressed highlight on clicked entities for [`HighlightPluginSettings::click_flash`],
+/// then restores the highlight for their current state.
+pub fn update_click_flash<T: Asset>(
+    time: Res<Time>,
+    settings: Res<HighlightPluginSettings>,
+    global_defaults: Res<GlobalHighlight<T>>,
+    mut clicks: EventReader<Pointer<Click>>,
+    mut flashing: Local<HashMap<Entity, Duration>>,
+    mut highlight_query: Query<(
+        &mut Handle<T>,
+        &PickingInteraction,
+        &InitialHighlight<T>,
+        Option<&Highlight<T>>,
+    )>,
+    #[cfg(feature = "selection")] selections: Query<&PickSelection>,
+) {
+    let now = time.elapsed();
+    if !settings.click_flash.is_zero() {
+        for click in clicks.read() {
+            if highlight_query.contains(click.target) {
+                flashing.insert(click.target, now + settings.click_flash);
+            }
+        }
+    } else {
+        clicks.clear();
+    }
+
+    flashing.retain(|entity, until| {
+        let Ok((mut asset, interaction, init_highlight, h_override)) =
+            highlight_query.get_mut(*entity)
+        else {
+            return false;
+        };
+        if now < *until {
+            asset.set_if_neq(global_defaults.pressed(&h_override));
+            return true;
+        }
+        // Restore the highlight for the current interaction state, now that the flash has ended.
+        #[cfg(feature = "selection")]
+        let is_selected = selections
+            .get(*entity)
+            .is_ok_and(|selection| selection.is_selected);
+        #[cfg(not(feature = "selection"))]
+        let is_selected = false;
+        *asset = global_defaults.for_state(
+            interaction,
+            is_selected,
+            &init_highlight.initial,
+            &h_override,
+        );
+        false
+    });
+}
+
+/// Applies the dragging highlight to entities between [`DragStart`] and [`DragEnd`], taking
+/// precedence over the other highlighting states. Entities without a dragging highlight are left
+/// alone, and use the pressed or hovered highlight as usual.
+///
+/// Drags are also ended when their pointer is cancelled with a [`PointerCancel`], so a dragged
+/// entity is never left highlighted if its pointer disappears without a [`DragEnd`].
+pub fn update_drag_highlight<T: Asset>(
+    global_defaults: Res<GlobalHighlight<T>>,
+    mut drag_start: EventReader<Pointer<DragStart>>,
+    mut drag_end: EventReader<Pointer<DragEnd>>,
+    mut cancels: EventReader<PointerCancel>,
+    mut dragged: Local<HashMap<Entity, PointerId>>,
+    mut highlight_query: Query<(
+        &mut Handle<T>,
+        &PickingInteraction,
+        &InitialHighlight<T>,
+        Option<&Highlight<T>>,
+    )>,
+    #[cfg(feature = "selection")] selections: Query<&PickSelection>,
+) {
+    dragged.extend(
+        drag_start
+            .read()
+            .map(|event| (event.target, event.pointer_id)),
+    );
+
+    let cancelled: HashSet<PointerId> = cancels.read().map(|event| event.pointer_id).collect();
+    let mut ended: Vec<Entity> = drag_end.read().map(|event| event.target).collect();
+    ended.extend(
+        dragged
+            .iter()
+            .filter(|(_, pointer_id)| cancelled.contains(*pointer_id))
+            .map(|(entity, _)| *entity),
+    );
+
+    for entity in ended {
+        if dragged.remove(&entity).is_none() {
+            continue;
+        }
+        let Ok((mut asset, interaction, init_highlight, h_override)) =
+            highlight_query.get_mut(entity)
+        else {
+            continue;
+        };
+        // Restore the highlight for the current interaction state, now that dragging has ended.
+        #[cfg(feature = "selection")]
+        let is_selected = selections
+            .get(entity)
+            .is_ok_and(|selection| selection.is_selected);
+        #[cfg(not(feature = "selection"))]
+        let is_selected = false;
+        *asset = global_defaults.for_state(
+            interaction,
+            is_selected,
+            &init_highlight.initial,
+            &h_override,
+        );
+    }
+
+    dragged.retain(|entity, _| {
+        let Ok((mut asset, _, _, h_override)) = highlight_query.get_mut(*entity) else {
+            return false;
+        };
+        if let Some(dragging) = global_defaults.dragging(&h_override) {
+            if *asset != dragging {
+                *asset = dragging;
+            }
+        }
+        true
+    });
+}
@@ -0,0 +1,218 @@
+//! Provides a virtual pointer for each connected gamepad, moved with the left stick.
+//!
+//! Add the [`GamepadPointerPlugin`] to spawn a pointer in the primary window for every connected
+//! gamepad. The left stick moves the pointer, and the south, east, and north face buttons press
+//! the primary, secondary, and middle pointer buttons. How the stick moves the pointer is tuned
+//! with the [`GamepadPointerSettings`] resource.
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_math::Vec2;
+use bevy_reflect::prelude::*;
+use bevy_render::camera::RenderTarget;
+use bevy_time::Time;
+use bevy_utils::tracing::debug;
+use bevy_window::{PrimaryWindow, Window, WindowRef};
+
+use bevy_picking_core::{
+    pointer::{InputMove, InputPress, Location, PointerButton, PointerId, PointerLocation, Uuid},
+    PickSet, PointerCoreBundle,
+};
+
+use crate::InputSet;
+
+/// The [`Uuid`] of gamepad pointers, with the gamepad id in the lower 64 bits.
+const GAMEPAD_POINTER_UUID: u128 = 0x6761_6d65_7061_6470_0000_0000_0000_0000;
+
+/// Adds a virtual pointer for every connected gamepad. See the [module docs](self).
+pub struct GamepadPointerPlugin;
+impl Plugin for GamepadPointerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GamepadPointerSettings>()
+            .add_systems(
+                First,
+                gamepad_pick_events
+                    .in_set(PickSet::Input)
+                    .in_set(InputSet::Collect),
+            )
+            .register_type::<GamepadPointer>()
+            .register_type::<GamepadPointerSettings>()
+            .register_type::<GamepadPointerCurve>();
+    }
+}
+
+/// Marks the pointer controlled by a gamepad. Spawned by [`gamepad_pick_events`], and despawned
+/// when the gamepad is disconnected.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct GamepadPointer {
+    /// The gamepad controlling this pointer.
+    pub gamepad: Gamepad,
+}
+
+impl GamepadPointer {
+    /// The [`PointerId`] of the pointer controlled by this `gamepad`.
+    pub fn pointer_id(gamepad: Gamepad) -> PointerId {
+        PointerId::Custom(Uuid::from_u128(GAMEPAD_POINTER_UUID | gamepad.id as u128))
+    }
+}
+
+/// Maps how far the stick is pushed past the deadzone, from `0.0` to `1.0`, to a fraction of
+/// [`GamepadPointerSettings::max_speed`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum GamepadPointerCurve {
+    /// The speed is proportional to how far the stick is pushed.
+    Linear,
+    /// The speed grows with the square of how far the stick is pushed, allowing slow, precise
+    /// movement near the center of the stick, and fast movement at the edge.
+    Quadratic,
+    /// The speed grows with how far the stick is pushed raised to this power.
+    Power(f32),
+}
+
+impl GamepadPointerCurve {
+    /// Maps the stick `input`, from `0.0` to `1.0`, to a fraction of the maximum speed.
+    pub fn apply(&self, input: f32) -> f32 {
+        let input = input.clamp(0.0, 1.0);
+        match self {
+            GamepadPointerCurve::Linear => input,
+            GamepadPointerCurve::Quadratic => input * input,
+            GamepadPointerCurve::Power(exponent) => input.powf(*exponent),
+        }
+    }
+}
+
+/// Tunes how gamepad sticks move their pointers. Used by the [`GamepadPointerPlugin`].
+#[derive(Resource, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Resource, Default)]
+pub struct GamepadPointerSettings {
+    /// How far the stick must be pushed, from `0.0` to `1.0`, before the pointer moves. This hides
+    /// the drift of sticks at rest.
+    pub deadzone: f32,
+    /// Maps how far the stick is pushed past the deadzone to the speed of the pointer.
+    pub acceleration_curve: GamepadPointerCurve,
+    /// The speed of the pointer when the stick is fully pushed, in logical pixels per second.
+    pub max_speed: f32,
+}
+
+impl Default for GamepadPointerSettings {
+    fn default() -> Self {
+        Self {
+            deadzone: 0.15,
+            acceleration_curve: GamepadPointerCurve::Quadratic,
+            max_speed: 1000.0,
+        }
+    }
+}
+
+impl GamepadPointerSettings {
+    /// The velocity of the pointer, in logical pixels per second, for this `stick` position.
+    pub fn velocity(&self, stick: Vec2) -> Vec2 {
+        let magnitude = stick.length().min(1.0);
+        if magnitude <= self.deadzone {
+            return Vec2::ZERO;
+        }
+        let input = (magnitude - self.deadzone) / (1.0 - self.deadzone).max(f32::EPSILON);
+        stick.normalize_or_zero() * self.acceleration_curve.apply(input) * self.max_speed
+    }
+}
+
+/// Spawns and despawns a pointer for each connected gamepad, and sends the pointer events of its
+/// stick and buttons to be processed by the core plugin.
+pub fn gamepad_pick_events(
+    // Input
+    time: Res<Time>,
+    settings: Res<GamepadPointerSettings>,
+    gamepads: Res<Gamepads>,
+    axes: Res<Axis<GamepadAxis>>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    pointers: Query<(Entity, &GamepadPointer, &PointerLocation)>,
+    // Output
+    mut commands: Commands,
+    mut pointer_move: EventWriter<InputMove>,
+    mut pointer_presses: EventWriter<InputPress>,
+) {
+    for (entity, pointer, _) in &pointers {
+        if !gamepads.contains(pointer.gamepad) {
+            debug!(
+                "Despawning pointer of disconnected gamepad {:?}",
+                pointer.gamepad
+            );
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let Ok((window_entity, window)) = windows.get_single() else {
+        return;
+    };
+    let Some(target) = RenderTarget::Window(WindowRef::Primary).normalize(Some(window_entity))
+    else {
+        return;
+    };
+    let window_size = Vec2::new(window.width(), window.height());
+
+    for gamepad in gamepads.iter() {
+        let pointer_id = GamepadPointer::pointer_id(gamepad);
+        let location = pointers
+            .iter()
+            .find(|(_, pointer, _)| pointer.gamepad == gamepad)
+            .map(|(.., location)| location.location().cloned());
+        let position = match location {
+            Some(Some(location)) if location.target == target => location.position,
+            Some(_) => window_size / 2.0,
+            None => {
+                debug!("Spawning pointer for gamepad {gamepad:?}");
+                commands.spawn((
+                    PointerCoreBundle::new(pointer_id),
+                    GamepadPointer { gamepad },
+                    #[cfg(feature = "selection")]
+                    bevy_picking_selection::PointerMultiselect::default(),
+                ));
+                let location = Location {
+                    target: target.clone(),
+                    position: window_size / 2.0,
+                };
+                pointer_move.send(InputMove::new(pointer_id, location, Vec2::ZERO));
+                continue;
+            }
+        };
+
+        let stick = Vec2::new(
+            axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX))
+                .unwrap_or_default(),
+            axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY))
+                .unwrap_or_default(),
+        );
+        // Stick up is positive, but window positions grow downwards.
+        let velocity = settings.velocity(stick) * Vec2::new(1.0, -1.0);
+        if velocity != Vec2::ZERO {
+            let new_position =
+                (position + velocity * time.delta_seconds()).clamp(Vec2::ZERO, window_size);
+            let location = Location {
+                target: target.clone(),
+                position: new_position,
+            };
+            pointer_move.send(InputMove::new(
+                pointer_id,
+                location,
+                new_position - position,
+            ));
+        }
+
+        for (button_type, button) in [
+            (GamepadButtonType::South, PointerButton::Primary),
+            (GamepadButtonType::East, PointerButton::Secondary),
+            (GamepadButtonType::North, PointerButton::Middle),
+        ] {
+            let gamepad_button = GamepadButton::new(gamepad, button_type);
+            if buttons.just_pressed(gamepad_button) {
+                pointer_presses.send(InputPress::new_down(pointer_id, button));
+            }
+            if buttons.just_released(gamepad_button) {
+                pointer_presses.send(InputPress::new_up(pointer_id, button));
+            }
+        }
+    }
+}
@@ -17,15 +17,32 @@
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_reflect::prelude::*;
+use bevy_render::camera::RenderTarget;
+use bevy_utils::tracing::debug;
+use bevy_window::{PrimaryWindow, Window, WindowFocused, WindowRef};
 
-use bevy_picking_core::PickSet;
+use bevy_picking_core::{
+    events::PointerCancel,
+    pointer::{InputPress, PointerButton, PointerId, PointerLocation, PointerPress},
+    PickSet,
+};
 
+pub mod gamepad;
 pub mod mouse;
+pub mod recording;
 pub mod touch;
 
 /// Common imports for `bevy_picking_input`.
 pub mod prelude {
-    pub use crate::{InputPlugin, InputPluginSettings};
+    pub use crate::{
+        gamepad::{
+            GamepadPointer, GamepadPointerCurve, GamepadPointerPlugin, GamepadPointerSettings,
+        },
+        mouse::PointerButtonMapping,
+        recording::{InputPlayer, InputRecorder, InputRecording, PickingPlayer, PickingRecorder},
+        touch::TouchSettings,
+        InputPlugin, InputPluginSettings, InputSet, NoPickingInput,
+    };
 }
 
 /// Adds mouse and touch inputs for picking pointers to your app. This is a default input plugin,
@@ -34,25 +51,116 @@ pub struct InputPlugin;
 impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<InputPluginSettings>()
+            .init_resource::<touch::TouchSettings>()
+            .init_resource::<touch::RecentTouchLifts>()
             .add_systems(Startup, mouse::spawn_mouse_pointer)
+            .configure_sets(
+                First,
+                (InputSet::Collect, InputSet::Flush)
+                    .chain()
+                    .in_set(PickSet::Input),
+            )
             .add_systems(
                 First,
                 (
                     touch::touch_pick_events.run_if(InputPluginSettings::is_touch_enabled),
                     mouse::mouse_pick_events.run_if(InputPluginSettings::is_mouse_enabled),
-                    // IMPORTANT: the commands must be flushed after `touch_pick_events` is run
-                    // because we need pointer spawning to happen immediately to prevent issues with
-                    // missed events during drag and drop.
-                    apply_deferred,
+                    cancel_unfocused_pointers.run_if(InputPluginSettings::is_only_focused_window),
                 )
                     .chain()
-                    .in_set(PickSet::Input),
+                    .in_set(InputSet::Collect),
             )
+            .add_systems(First, apply_deferred.in_set(InputSet::Flush))
             .add_systems(
                 Last,
                 touch::deactivate_touch_pointers.run_if(InputPluginSettings::is_touch_enabled),
             )
-            .register_type::<InputPluginSettings>();
+            .register_type::<InputPluginSettings>()
+            .register_type::<NoPickingInput>()
+            .register_type::<mouse::PointerButtonMapping>()
+            .register_type::<touch::TouchSettings>();
+    }
+}
+
+/// Orders input systems within [`PickSet::Input`], in the [`First`] schedule.
+///
+/// Input systems may spawn pointers with commands, like [`touch::touch_pick_events`] does for new
+/// touches. Those commands must be applied before the input events are processed, otherwise the
+/// events of a new pointer are missed, which breaks drag and drop. Custom input systems should be
+/// added to [`InputSet::Collect`], so any pointers they spawn exist by the end of
+/// [`InputSet::Flush`].
+///
+/// ```ignore
+/// app.add_systems(First, my_gamepad_pointer.in_set(InputSet::Collect));
+/// ```
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum InputSet {
+    /// Reads input devices, spawns pointers, and sends pointer input events.
+    Collect,
+    /// Applies the commands of the [`InputSet::Collect`] systems, so spawned pointers exist before
+    /// their input events are processed.
+    Flush,
+}
+
+/// A marker component for windows that should not produce picking inputs.
+///
+/// By default, mouse and touch inputs from all windows are sent to picking pointers. Add this to a
+/// window entity to ignore the inputs of that window, for example in an app with a tool window and
+/// a viewport window, where only the viewport should drive picking.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
+pub struct NoPickingInput;
+
+/// Returns `true` if inputs from this window should be sent to picking pointers, based on
+/// [`NoPickingInput`] and [`InputPluginSettings::only_focused_window`].
+pub(crate) fn window_accepts_input(
+    window: Entity,
+    settings: &InputPluginSettings,
+    windows: &Query<&Window>,
+    ignored_windows: &Query<(), With<NoPickingInput>>,
+) -> bool {
+    if ignored_windows.contains(window) {
+        return false;
+    }
+    !settings.only_focused_window || windows.get(window).is_ok_and(|window| window.focused)
+}
+
+/// When [`InputPluginSettings::only_focused_window`] is enabled, releases all buttons of pointers
+/// in a window that lost focus, and cancels them. This ends any drags started in that window.
+pub fn cancel_unfocused_pointers(
+    // Input
+    mut focus_events: EventReader<WindowFocused>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    pointers: Query<(&PointerId, &PointerLocation, &PointerPress)>,
+    // Output
+    mut input_presses: EventWriter<InputPress>,
+    mut cancel_events: EventWriter<PointerCancel>,
+) {
+    for event in focus_events.read().filter(|event| !event.focused) {
+        let Some(target) = RenderTarget::Window(WindowRef::Entity(event.window))
+            .normalize(primary_window.get_single().ok())
+        else {
+            continue;
+        };
+        for (pointer_id, location, press) in &pointers {
+            if location.location().map(|location| &location.target) != Some(&target) {
+                continue;
+            }
+            debug!("Cancelling pointer {pointer_id:?} in unfocused window");
+            for button in PointerButton::iter() {
+                let is_pressed = match button {
+                    PointerButton::Primary => press.is_primary_pressed(),
+                    PointerButton::Secondary => press.is_secondary_pressed(),
+                    PointerButton::Middle => press.is_middle_pressed(),
+                };
+                if is_pressed {
+                    input_presses.send(InputPress::new_up(*pointer_id, button));
+                }
+            }
+            cancel_events.send(PointerCancel {
+                pointer_id: *pointer_id,
+            });
+        }
     }
 }
 
@@ -67,6 +175,22 @@ pub struct InputPluginSettings {
     pub is_touch_enabled: bool,
     /// Should mouse inputs be updated?
     pub is_mouse_enabled: bool,
+    /// The global [`PointerButtonMapping`](mouse::PointerButtonMapping) of the mouse, which maps
+    /// each [`MouseButton`](bevy_input::mouse::MouseButton) to a pointer button. Changes take
+    /// effect immediately, unless the mouse pointer has its own mapping component. Defaults to
+    /// [`PointerButtonMapping::right_handed`](mouse::PointerButtonMapping::right_handed).
+    pub mouse_button_mapping: mouse::PointerButtonMapping,
+    /// Should inputs from windows that are not focused be ignored? Off by default.
+    ///
+    /// This prevents accidental interactions with background windows in multi-window apps. When a
+    /// window loses focus, the buttons of pointers in that window are released, ending any drags.
+    pub only_focused_window: bool,
+    /// Should mouse events that are likely synthesized from touches be ignored? Off by default.
+    ///
+    /// Some platforms emit mouse events alongside touch events, which causes a single tap to
+    /// trigger both the touch pointer and the mouse pointer. See
+    /// [`SyntheticMouseFilter`](mouse::SyntheticMouseFilter) for details on how these are detected.
+    pub ignore_synthetic_mouse: bool,
 }
 
 impl Default for InputPluginSettings {
@@ -74,6 +198,9 @@ impl Default for InputPluginSettings {
         Self {
             is_touch_enabled: true,
             is_mouse_enabled: true,
+            mouse_button_mapping: mouse::PointerButtonMapping::right_handed(),
+            only_focused_window: false,
+            ignore_synthetic_mouse: false,
         }
     }
 }
@@ -85,4 +212,7 @@ impl InputPluginSettings {
     fn is_mouse_enabled(state: Res<Self>) -> bool {
         state.is_mouse_enabled
     }
+    fn is_only_focused_window(state: Res<Self>) -> bool {
+        state.only_focused_window
+    }
 }
@@ -1,9 +1,16 @@
 //! Provides sensible defaults for mouse picking inputs.
 
 use bevy_ecs::prelude::*;
-use bevy_input::{mouse::MouseButtonInput, prelude::*, ButtonState};
+use bevy_input::{
+    mouse::MouseButtonInput,
+    prelude::*,
+    touch::{TouchInput, TouchPhase},
+    ButtonState,
+};
 use bevy_math::Vec2;
+use bevy_reflect::prelude::*;
 use bevy_render::camera::RenderTarget;
+use bevy_utils::HashMap;
 use bevy_window::{CursorMoved, PrimaryWindow, Window, WindowRef};
 
 use bevy_picking_core::{
@@ -11,6 +18,68 @@ use bevy_picking_core::{
     PointerCoreBundle,
 };
 
+use crate::{window_accepts_input, InputPluginSettings, NoPickingInput};
+
+/// Maps physical mouse buttons to [`PointerButton`]s, for example to bind the middle mouse button
+/// to the secondary button, or to make the right mouse button the primary button for left-handed
+/// users. Because the mapping is applied when inputs are read, all downstream logic, like selection
+/// and clicking, can keep using [`PointerButton::Primary`].
+///
+/// The mouse is mapped globally with [`InputPluginSettings::mouse_button_mapping`], which can be
+/// changed at any time. Add this component to the mouse pointer to override the global mapping.
+///
+/// Any [`MouseButton`], including [`MouseButton::Back`], [`MouseButton::Forward`], and
+/// [`MouseButton::Other`], can be mapped. Mouse buttons that are not mapped are ignored.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct PointerButtonMapping {
+    /// The pointer button each mouse button is mapped to.
+    pub buttons: HashMap<MouseButton, PointerButton>,
+}
+
+impl PointerButtonMapping {
+    /// The default mapping: left is primary, right is secondary, and middle is middle.
+    pub fn right_handed() -> Self {
+        Self {
+            buttons: HashMap::default(),
+        }
+        .with(MouseButton::Left, PointerButton::Primary)
+        .with(MouseButton::Right, PointerButton::Secondary)
+        .with(MouseButton::Middle, PointerButton::Middle)
+    }
+
+    /// Swaps the primary and secondary buttons of the default mapping: right is primary, left is
+    /// secondary.
+    pub fn left_handed() -> Self {
+        Self::right_handed()
+            .with(MouseButton::Left, PointerButton::Secondary)
+            .with(MouseButton::Right, PointerButton::Primary)
+    }
+
+    /// Maps the mouse `button` to the `pointer_button`, replacing its previous mapping.
+    pub fn with(mut self, button: MouseButton, pointer_button: PointerButton) -> Self {
+        self.buttons.insert(button, pointer_button);
+        self
+    }
+
+    /// Removes the mapping of the mouse `button`, so it is ignored.
+    pub fn without(mut self, button: MouseButton) -> Self {
+        self.buttons.remove(&button);
+        self
+    }
+
+    /// Returns the [`PointerButton`] mapped to this [`MouseButton`], if any.
+    pub fn get(&self, button: MouseButton) -> Option<PointerButton> {
+        self.buttons.get(&button).copied()
+    }
+}
+
+impl Default for PointerButtonMapping {
+    fn default() -> Self {
+        Self::right_handed()
+    }
+}
+
 /// Spawns the default mouse pointer.
 pub fn spawn_mouse_pointer(mut commands: Commands) {
     commands.spawn((
@@ -20,18 +89,88 @@ pub fn spawn_mouse_pointer(mut commands: Commands) {
     ));
 }
 
+/// Tracks touch positions to detect synthetic mouse events, which some platforms emit alongside
+/// touch events. Used when [`InputPluginSettings::ignore_synthetic_mouse`] is enabled.
+///
+/// Winit does not report whether a mouse event was synthesized from a touch, so mouse events are
+/// instead considered synthetic when they occur at the position of an active touch, or where the
+/// latest touch ended.
+#[derive(Debug, Default)]
+pub struct SyntheticMouseFilter {
+    active_touches: HashMap<u64, Vec2>,
+    last_touch_end: Option<Vec2>,
+    is_cursor_synthetic: bool,
+}
+
+impl SyntheticMouseFilter {
+    /// The maximum distance, in logical pixels, between a mouse event and a touch for the mouse
+    /// event to be considered synthetic.
+    pub const TOLERANCE: f32 = 4.0;
+
+    /// Update the tracked touches.
+    pub fn track_touch(&mut self, touch: &TouchInput) {
+        match touch.phase {
+            TouchPhase::Started | TouchPhase::Moved => {
+                self.active_touches.insert(touch.id, touch.position);
+            }
+            TouchPhase::Ended | TouchPhase::Canceled => {
+                self.active_touches.remove(&touch.id);
+                self.last_touch_end = Some(touch.position);
+            }
+        }
+    }
+
+    /// Returns `true` if a cursor move to this position is likely synthesized from a touch. This
+    /// also decides if the following mouse button presses are synthetic.
+    pub fn is_cursor_synthetic(&mut self, position: Vec2) -> bool {
+        let is_near = |touch: &Vec2| touch.distance(position) <= Self::TOLERANCE;
+        let is_at_touch_end = self.last_touch_end.as_ref().is_some_and(is_near);
+        if !is_at_touch_end {
+            // The cursor moved away from the touch, so it's being driven by a real mouse again.
+            self.last_touch_end = None;
+        }
+        self.is_cursor_synthetic = is_at_touch_end || self.active_touches.values().any(is_near);
+        self.is_cursor_synthetic
+    }
+
+    /// Returns `true` if a mouse button press is likely synthesized from a touch.
+    pub fn is_press_synthetic(&self) -> bool {
+        self.is_cursor_synthetic
+    }
+}
+
 /// Sends mouse pointer events to be processed by the core plugin
 pub fn mouse_pick_events(
     // Input
+    settings: Res<InputPluginSettings>,
     windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    all_windows: Query<&Window>,
+    ignored_windows: Query<(), With<NoPickingInput>>,
+    button_mappings: Query<(&PointerId, &PointerButtonMapping)>,
     mut cursor_moves: EventReader<CursorMoved>,
     mut cursor_last: Local<Vec2>,
     mut mouse_inputs: EventReader<MouseButtonInput>,
+    mut touches: EventReader<TouchInput>,
+    mut synthetic_filter: Local<SyntheticMouseFilter>,
     // Output
     mut pointer_move: EventWriter<InputMove>,
     mut pointer_presses: EventWriter<InputPress>,
 ) {
+    if settings.ignore_synthetic_mouse {
+        for touch in touches.read() {
+            synthetic_filter.track_touch(touch);
+        }
+    } else {
+        touches.clear();
+    }
+
     for event in cursor_moves.read() {
+        if !window_accepts_input(event.window, &settings, &all_windows, &ignored_windows) {
+            continue;
+        }
+        if settings.ignore_synthetic_mouse && synthetic_filter.is_cursor_synthetic(event.position) {
+            continue;
+        }
         pointer_move.send(InputMove::new(
             PointerId::Mouse,
             Location {
@@ -51,14 +190,20 @@ pub fn mouse_pick_events(
         *cursor_last = event.position;
     }
 
+    let button_mapping = button_mappings
+        .iter()
+        .find_map(|(id, mapping)| id.is_mouse().then_some(mapping))
+        .unwrap_or(&settings.mouse_button_mapping);
+
     for input in mouse_inputs.read() {
-        let button = match input.button {
-            MouseButton::Left => PointerButton::Primary,
-            MouseButton::Right => PointerButton::Secondary,
-            MouseButton::Middle => PointerButton::Middle,
-            MouseButton::Other(_) => continue,
-            MouseButton::Back => continue,
-            MouseButton::Forward => continue,
+        if !window_accepts_input(input.window, &settings, &all_windows, &ignored_windows) {
+            continue;
+        }
+        if settings.ignore_synthetic_mouse && synthetic_filter.is_press_synthetic() {
+            continue;
+        }
+        let Some(button) = button_mapping.get(input.button) else {
+            continue;
         };
 
         match input.state {
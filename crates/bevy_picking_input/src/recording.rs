@@ -0,0 +1,595 @@
+//! Records pointer inputs, and plays them back.
+//!
+//! Add the [`PickingRecorder`] plugin, then call [`InputRecorder::start`] and
+//! [`InputRecorder::stop`] to capture every [`InputMove`] and [`InputPress`] sent in between. The
+//! resulting [`InputRecording`] can be saved to disk, and replayed with the [`PickingPlayer`]
+//! plugin by calling [`InputPlayer::play`]. Because pointers are not coupled to the input hardware,
+//! replayed inputs are indistinguishable from real ones, which is useful for automated UI testing,
+//! demos, and reproducing bugs.
+//!
+//! Inputs are replayed on the same frames they were recorded, relative to the start of the
+//! recording. Replaying inputs does not stop the mouse and touch inputs, disable them with
+//! [`InputPluginSettings`](crate::InputPluginSettings) if they should not interfere.
+
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    time::Duration,
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_render::camera::{NormalizedRenderTarget, RenderTarget};
+use bevy_time::Time;
+use bevy_utils::{tracing::debug, HashMap, HashSet};
+use bevy_window::{PrimaryWindow, WindowRef};
+
+use bevy_picking_core::{
+    pointer::{InputMove, InputPress, Location, PointerButton, PointerId, PressDirection, Uuid},
+    PickSet, PointerCoreBundle,
+};
+
+use crate::InputSet;
+
+/// The first line of a saved [`InputRecording`], used to detect the format.
+const HEADER: &str = "bevy_picking_recording v1";
+
+/// Adds the [`InputRecorder`] resource, which records pointer inputs while it is started.
+pub struct PickingRecorder;
+impl Plugin for PickingRecorder {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputRecorder>()
+            .add_systems(First, record_inputs.in_set(PickSet::PostInput));
+    }
+}
+
+/// Adds the [`InputPlayer`] resource, which replays an [`InputRecording`] while it is playing.
+pub struct PickingPlayer;
+impl Plugin for PickingPlayer {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputPlayer>().add_systems(
+            First,
+            play_inputs.in_set(PickSet::Input).in_set(InputSet::Collect),
+        );
+    }
+}
+
+/// A pointer input captured by the [`InputRecorder`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordedInput {
+    /// A recorded [`InputMove`].
+    Move {
+        /// The [`PointerId`] of the pointer that moved.
+        pointer_id: PointerId,
+        /// The render target the pointer moved on. Targets are not saved to disk, so this is `None`
+        /// for loaded recordings, and the move is replayed in the primary window.
+        target: Option<NormalizedRenderTarget>,
+        /// The position of the pointer on the target.
+        position: Vec2,
+        /// The distance moved since the last move of this pointer.
+        delta: Vec2,
+    },
+    /// A recorded [`InputPress`].
+    Press(InputPress),
+}
+
+impl RecordedInput {
+    /// The [`PointerId`] of the pointer this input was sent to.
+    pub fn pointer_id(&self) -> PointerId {
+        match self {
+            RecordedInput::Move { pointer_id, .. } => *pointer_id,
+            RecordedInput::Press(press) => press.pointer_id,
+        }
+    }
+}
+
+/// A [`RecordedInput`], and when it was recorded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    /// The frame the input was sent, counted from the first frame of the recording.
+    pub frame: u32,
+    /// The time the input was sent, relative to the first frame of the recording.
+    pub time: Duration,
+    /// The recorded input.
+    pub input: RecordedInput,
+}
+
+/// A sequence of pointer inputs, in the order they were sent.
+///
+/// Recordings are saved in a line based text format. The first line is a header, followed by one
+/// line per event: the frame, the time in seconds, and the input, separated by spaces:
+///
+/// ```text
+/// bevy_picking_recording v1
+/// 0 0.000000 move mouse 120.5 300 0 0
+/// 3 0.050000 press mouse down primary
+/// 7 0.116666 press touch:1 up secondary
+/// ```
+///
+/// Pointers are written as `mouse`, `touch:<id>`, or `custom:<uuid>`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct InputRecording {
+    /// The recorded events, sorted by frame.
+    pub events: Vec<RecordedEvent>,
+}
+
+impl InputRecording {
+    /// Returns `true` if nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// The number of frames spanned by this recording.
+    pub fn frame_count(&self) -> u32 {
+        self.events.last().map_or(0, |event| event.frame + 1)
+    }
+
+    /// Writes this recording to a file at `path`, replacing it if it exists.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Reads a recording from the file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    /// Writes this recording to `writer` in the text format described in [`InputRecording`].
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{HEADER}")?;
+        for event in &self.events {
+            write!(writer, "{} {:.6} ", event.frame, event.time.as_secs_f64())?;
+            match &event.input {
+                RecordedInput::Move {
+                    pointer_id,
+                    position,
+                    delta,
+                    ..
+                } => writeln!(
+                    writer,
+                    "move {} {} {} {} {}",
+                    DisplayPointerId(*pointer_id),
+                    position.x,
+                    position.y,
+                    delta.x,
+                    delta.y
+                )?,
+                RecordedInput::Press(press) => {
+                    let direction = match press.direction {
+                        PressDirection::Down => "down",
+                        PressDirection::Up => "up",
+                    };
+                    let button = match press.button {
+                        PointerButton::Primary => "primary",
+                        PointerButton::Secondary => "secondary",
+                        PointerButton::Middle => "middle",
+                    };
+                    writeln!(
+                        writer,
+                        "press {} {direction} {button}",
+                        DisplayPointerId(press.pointer_id)
+                    )?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a recording from `reader`, in the text format described in [`InputRecording`].
+    /// Returns an [`io::ErrorKind::InvalidData`] error if the input is malformed.
+    pub fn read_from(reader: impl BufRead) -> io::Result<Self> {
+        let mut lines = reader.lines();
+        match lines.next().transpose()? {
+            Some(header) if header.trim() == HEADER => (),
+            _ => return Err(invalid_data(1, "missing recording header")),
+        }
+        let mut events = Vec::new();
+        for (index, line) in lines.enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let event = parse_event(&line).ok_or_else(|| invalid_data(index + 2, &line))?;
+            events.push(event);
+        }
+        Ok(Self { events })
+    }
+}
+
+fn invalid_data(line: usize, message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid input recording at line {line}: {message}"),
+    )
+}
+
+fn parse_event(line: &str) -> Option<RecordedEvent> {
+    let mut fields = line.split_whitespace();
+    let frame = fields.next()?.parse().ok()?;
+    let time = Duration::try_from_secs_f64(fields.next()?.parse().ok()?).ok()?;
+    let kind = fields.next()?;
+    let pointer_id = parse_pointer_id(fields.next()?)?;
+    let input = match kind {
+        "move" => {
+            let mut next_f32 = || fields.next()?.parse::<f32>().ok();
+            RecordedInput::Move {
+                pointer_id,
+                target: None,
+                position: Vec2::new(next_f32()?, next_f32()?),
+                delta: Vec2::new(next_f32()?, next_f32()?),
+            }
+        }
+        "press" => {
+            let direction = match fields.next()? {
+                "down" => PressDirection::Down,
+                "up" => PressDirection::Up,
+                _ => return None,
+            };
+            let button = match fields.next()? {
+                "primary" => PointerButton::Primary,
+                "secondary" => PointerButton::Secondary,
+                "middle" => PointerButton::Middle,
+                _ => return None,
+            };
+            RecordedInput::Press(InputPress {
+                pointer_id,
+                direction,
+                button,
+            })
+        }
+        _ => return None,
+    };
+    fields
+        .next()
+        .is_none()
+        .then_some(RecordedEvent { frame, time, input })
+}
+
+fn parse_pointer_id(text: &str) -> Option<PointerId> {
+    match text.split_once(':') {
+        None if text == "mouse" => Some(PointerId::Mouse),
+        Some(("touch", id)) => id.parse().ok().map(PointerId::Touch),
+        Some(("custom", uuid)) => Uuid::parse_str(uuid).ok().map(PointerId::Custom),
+        _ => None,
+    }
+}
+
+struct DisplayPointerId(PointerId);
+impl fmt::Display for DisplayPointerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            PointerId::Mouse => write!(f, "mouse"),
+            PointerId::Touch(id) => write!(f, "touch:{id}"),
+            PointerId::Custom(uuid) => write!(f, "custom:{uuid}"),
+        }
+    }
+}
+
+/// Records the [`InputMove`] and [`InputPress`] events sent between [`InputRecorder::start`] and
+/// [`InputRecorder::stop`]. Added by the [`PickingRecorder`] plugin.
+#[derive(Resource, Debug, Default)]
+pub struct InputRecorder {
+    is_recording: bool,
+    /// The frame and time the recording started, set on the first recorded frame.
+    start: Option<(u32, Duration)>,
+    frame: u32,
+    recording: InputRecording,
+}
+
+impl InputRecorder {
+    /// Starts a new recording, discarding any inputs recorded so far.
+    pub fn start(&mut self) {
+        self.is_recording = true;
+        self.start = None;
+        self.recording = InputRecording::default();
+    }
+
+    /// Stops recording, and returns the inputs recorded since [`InputRecorder::start`].
+    pub fn stop(&mut self) -> InputRecording {
+        self.is_recording = false;
+        std::mem::take(&mut self.recording)
+    }
+
+    /// Returns `true` if inputs are being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.is_recording
+    }
+
+    /// The inputs recorded so far.
+    pub fn recording(&self) -> &InputRecording {
+        &self.recording
+    }
+}
+
+/// Appends the input events sent this frame to the [`InputRecorder`], while it is recording.
+pub fn record_inputs(
+    time: Res<Time>,
+    mut recorder: ResMut<InputRecorder>,
+    mut input_moves: EventReader<InputMove>,
+    mut input_presses: EventReader<InputPress>,
+) {
+    let recorder = recorder.as_mut();
+    recorder.frame = recorder.frame.wrapping_add(1);
+    if !recorder.is_recording {
+        input_moves.clear();
+        input_presses.clear();
+        return;
+    }
+
+    let (start_frame, start_time) = *recorder
+        .start
+        .get_or_insert((recorder.frame, time.elapsed()));
+    let frame = recorder.frame.wrapping_sub(start_frame);
+    let time = time.elapsed().saturating_sub(start_time);
+
+    let moves = input_moves.read().map(|event| RecordedInput::Move {
+        pointer_id: event.pointer_id,
+        target: Some(event.location.target.clone()),
+        position: event.location.position,
+        delta: event.delta,
+    });
+    let presses = input_presses.read().copied().map(RecordedInput::Press);
+    recorder
+        .recording
+        .events
+        .extend(
+            moves
+                .chain(presses)
+                .map(|input| RecordedEvent { frame, time, input }),
+        );
+}
+
+/// Replays an [`InputRecording`] by sending its inputs as [`InputMove`] and [`InputPress`] events.
+/// Added by the [`PickingPlayer`] plugin.
+///
+/// Pointers that don't exist when one of their inputs is replayed are spawned, so recorded touches
+/// and custom pointers can be replayed. Use [`InputPlayer::remap_pointer`] to replay the inputs of
+/// a recorded pointer with a different pointer. Spawned pointers are not despawned when playback
+/// ends.
+#[derive(Resource, Debug, Default)]
+pub struct InputPlayer {
+    recording: InputRecording,
+    is_playing: bool,
+    frame: u32,
+    next_event: usize,
+    pointer_remap: HashMap<PointerId, PointerId>,
+}
+
+impl InputPlayer {
+    /// Starts replaying `recording` on the next frame, replacing any recording being played.
+    pub fn play(&mut self, recording: InputRecording) {
+        self.recording = recording;
+        self.is_playing = true;
+        self.frame = 0;
+        self.next_event = 0;
+    }
+
+    /// Stops replaying the current recording.
+    pub fn stop(&mut self) {
+        self.is_playing = false;
+    }
+
+    /// Returns `true` if a recording is being replayed.
+    pub fn is_playing(&self) -> bool {
+        self.is_playing
+    }
+
+    /// Replays the inputs recorded for the pointer `from` with the pointer `to`.
+    pub fn remap_pointer(&mut self, from: PointerId, to: PointerId) -> &mut Self {
+        self.pointer_remap.insert(from, to);
+        self
+    }
+
+    /// Removes all pointer remappings added with [`InputPlayer::remap_pointer`].
+    pub fn clear_remapping(&mut self) {
+        self.pointer_remap.clear();
+    }
+}
+
+/// Sends the inputs of the [`InputPlayer`]'s recording that were recorded on the current frame.
+pub fn play_inputs(
+    mut player: ResMut<InputPlayer>,
+    pointers: Query<&PointerId>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut commands: Commands,
+    mut input_moves: EventWriter<InputMove>,
+    mut input_presses: EventWriter<InputPress>,
+) {
+    let player = player.as_mut();
+    if !player.is_playing {
+        return;
+    }
+
+    let mut spawned = HashSet::new();
+    while let Some(event) = player.recording.events.get(player.next_event) {
+        if event.frame > player.frame {
+            break;
+        }
+        player.next_event += 1;
+
+        let recorded_id = event.input.pointer_id();
+        let pointer_id = *player
+            .pointer_remap
+            .get(&recorded_id)
+            .unwrap_or(&recorded_id);
+        if !pointers.iter().any(|id| *id == pointer_id) && spawned.insert(pointer_id) {
+            debug!("Spawning pointer {pointer_id:?} to replay its inputs");
+            commands.spawn(PointerCoreBundle::new(pointer_id));
+        }
+
+        match &event.input {
+            RecordedInput::Move {
+                target,
+                position,
+                delta,
+                ..
+            } => {
+                let Some(target) = target.clone().or_else(|| {
+                    RenderTarget::Window(WindowRef::Primary)
+                        .normalize(primary_window.get_single().ok())
+                }) else {
+                    continue;
+                };
+                let location = Location {
+                    target,
+                    position: *position,
+                };
+                input_moves.send(InputMove::new(pointer_id, location, *delta));
+            }
+            RecordedInput::Press(press) => {
+                input_presses.send(InputPress {
+                    pointer_id,
+                    ..*press
+                });
+            }
+        }
+    }
+
+    player.frame += 1;
+    if player.next_event >= player.recording.events.len() {
+        debug!("Finished replaying input recording");
+        player.is_playing = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::event::ManualEventReader;
+
+    use super::*;
+
+    fn event(frame: u32, millis: u64, input: RecordedInput) -> RecordedEvent {
+        RecordedEvent {
+            frame,
+            time: Duration::from_millis(millis),
+            input,
+        }
+    }
+
+    fn press(pointer_id: PointerId, direction: PressDirection) -> RecordedInput {
+        RecordedInput::Press(InputPress {
+            pointer_id,
+            direction,
+            button: PointerButton::Secondary,
+        })
+    }
+
+    #[test]
+    fn recording_round_trip() {
+        let custom = PointerId::Custom(Uuid::from_u128(0x1234_5678_9abc_def0));
+        let recording = InputRecording {
+            events: vec![
+                event(
+                    0,
+                    0,
+                    RecordedInput::Move {
+                        pointer_id: PointerId::Mouse,
+                        target: None,
+                        position: Vec2::new(120.5, 300.0),
+                        delta: Vec2::new(-1.25, 0.0),
+                    },
+                ),
+                event(3, 250, press(PointerId::Touch(1), PressDirection::Down)),
+                event(7, 500, press(custom, PressDirection::Up)),
+            ],
+        };
+        let mut text = Vec::new();
+        recording.write_to(&mut text).unwrap();
+
+        assert_eq!(InputRecording::read_from(&text[..]).unwrap(), recording);
+    }
+
+    #[test]
+    fn malformed_recordings_are_rejected() {
+        let read = |text: &str| InputRecording::read_from(text.as_bytes());
+        assert!(read(&format!("{HEADER}\n0 0.0 press mouse down primary\n")).is_ok());
+
+        for text in [
+            "",
+            "0 0.0 press mouse down primary\n",
+            "bevy_picking_recording v2\n",
+        ] {
+            let error = read(text).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+        for line in [
+            "0 0.0 press mouse down",
+            "0 0.0 press mouse down primary extra",
+            "0 0.0 press mouse sideways primary",
+            "0 0.0 press pen down primary",
+            "0 0.0 press touch:x down primary",
+            "0 0.0 press custom:nope down primary",
+            "0 0.0 move mouse 1 2 3",
+            "0 0.0 scroll mouse 1 2",
+            "x 0.0 press mouse down primary",
+            "0 -1.0 press mouse down primary",
+        ] {
+            let error = read(&format!("{HEADER}\n{line}\n")).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{line}");
+            assert!(error.to_string().contains("line 2"), "{line}");
+        }
+    }
+
+    #[test]
+    fn inputs_are_replayed_on_their_frame() {
+        let mut app = App::new();
+        app.add_event::<InputMove>()
+            .add_event::<InputPress>()
+            .add_plugins(PickingPlayer);
+        let target = RenderTarget::Window(WindowRef::Entity(Entity::PLACEHOLDER))
+            .normalize(None)
+            .unwrap();
+        let recording = InputRecording {
+            events: vec![
+                event(
+                    0,
+                    0,
+                    RecordedInput::Move {
+                        pointer_id: PointerId::Mouse,
+                        target: Some(target),
+                        position: Vec2::ONE,
+                        delta: Vec2::ZERO,
+                    },
+                ),
+                event(2, 50, press(PointerId::Touch(1), PressDirection::Down)),
+            ],
+        };
+        app.world_mut()
+            .resource_mut::<InputPlayer>()
+            .remap_pointer(PointerId::Touch(1), PointerId::Mouse)
+            .play(recording);
+
+        let mut moves = ManualEventReader::<InputMove>::default();
+        let mut presses = ManualEventReader::<InputPress>::default();
+        let mut sent_per_frame = Vec::new();
+        for _ in 0..4 {
+            app.update();
+            let world = app.world();
+            let moves = moves.read(world.resource::<Events<InputMove>>());
+            let presses: Vec<_> = presses
+                .read(world.resource::<Events<InputPress>>())
+                .map(|press| press.pointer_id)
+                .collect();
+            sent_per_frame.push((moves.count(), presses));
+        }
+
+        assert_eq!(
+            sent_per_frame,
+            vec![
+                (1, vec![]),
+                (0, vec![]),
+                (0, vec![PointerId::Mouse]),
+                (0, vec![]),
+            ]
+        );
+        assert!(!app.world().resource::<InputPlayer>().is_playing());
+        let mut pointers = app.world_mut().query::<&PointerId>();
+        let pointers: Vec<_> = pointers.iter(app.world()).copied().collect();
+        assert_eq!(pointers, vec![PointerId::Mouse]);
+    }
+}
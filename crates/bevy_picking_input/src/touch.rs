@@ -1,12 +1,16 @@
 //! Provides sensible defaults for touch picking inputs.
 
+use std::time::Duration;
+
 use bevy_ecs::prelude::*;
 use bevy_hierarchy::DespawnRecursiveExt;
 use bevy_input::touch::{TouchInput, TouchPhase};
 use bevy_math::Vec2;
+use bevy_reflect::prelude::*;
 use bevy_render::camera::RenderTarget;
+use bevy_time::Time;
 use bevy_utils::{tracing::debug, HashMap, HashSet};
-use bevy_window::{PrimaryWindow, WindowRef};
+use bevy_window::{PrimaryWindow, Window, WindowRef};
 
 use bevy_picking_core::{
     events::PointerCancel,
@@ -14,6 +18,37 @@ use bevy_picking_core::{
     PointerCoreBundle,
 };
 
+use crate::{window_accepts_input, InputPluginSettings, NoPickingInput};
+
+/// Settings for touch inputs.
+#[derive(Resource, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Resource, Default)]
+pub struct TouchSettings {
+    /// If a touch ends, and a new touch with the same ID starts within this duration, the new touch
+    /// is treated as a continuation of the old one, instead of a new pointer. This keeps the
+    /// pointer pressed, so a quick lift-and-retap does not end an ongoing drag.
+    ///
+    /// The pointer is only released once this duration has elapsed without a retap, which delays
+    /// the release of every touch by this amount. Disabled when zero, which is the default.
+    pub retap_window: Duration,
+    /// When enabled, the press of a new touch is sent one frame after the touch starts. Touches
+    /// have no hover phase, so this gives the touched entity a frame where it is only hovered,
+    /// receiving [`Over`](bevy_picking_core::events::Over) and showing its hover highlight before
+    /// it is pressed. Touches that end in the frame they start are pressed immediately, so taps
+    /// are never lost. Disabled by default.
+    pub emit_hover_on_press: bool,
+}
+
+/// Touches that ended recently, which may be continued by a retap. See
+/// [`TouchSettings::retap_window`].
+#[derive(Resource, Debug, Default)]
+pub struct RecentTouchLifts {
+    /// The position and time each recently lifted touch ended.
+    lifts: HashMap<u64, (Vec2, Duration)>,
+    /// Touches whose retap window has expired, and whose pointers should be despawned.
+    expired: Vec<u64>,
+}
+
 /// Sends touch pointer events to be consumed by the core plugin
 ///
 /// IMPORTANT: the commands must be flushed after this system is run because we need spawning to
@@ -21,16 +56,40 @@ use bevy_picking_core::{
 pub fn touch_pick_events(
     // Input
     mut touches: EventReader<TouchInput>,
+    time: Res<Time>,
+    settings: Res<InputPluginSettings>,
+    touch_settings: Res<TouchSettings>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<&Window>,
+    ignored_windows: Query<(), With<NoPickingInput>>,
     // Local
     mut location_cache: Local<HashMap<u64, TouchInput>>,
+    mut pending_presses: Local<HashSet<u64>>,
     // Output
+    mut recent_lifts: ResMut<RecentTouchLifts>,
     mut commands: Commands,
     mut input_moves: EventWriter<InputMove>,
     mut input_presses: EventWriter<InputPress>,
     mut cancel_events: EventWriter<PointerCancel>,
 ) {
+    // Press the touches that were only hovering last frame. See `emit_hover_on_press`.
+    for id in pending_presses.drain() {
+        input_presses.send(InputPress::new_down(
+            PointerId::Touch(id),
+            PointerButton::Primary,
+        ));
+    }
+
     for touch in touches.read() {
+        if ignored_windows.contains(touch.window) {
+            continue;
+        }
+        // Touches that already started are always allowed to end, so their pointers are cleaned up.
+        let is_ending = matches!(touch.phase, TouchPhase::Ended | TouchPhase::Canceled);
+        if !is_ending && !window_accepts_input(touch.window, &settings, &windows, &ignored_windows)
+        {
+            continue;
+        }
         let pointer = PointerId::Touch(touch.id);
         let location = Location {
             target: match RenderTarget::Window(WindowRef::Entity(touch.window))
@@ -41,7 +100,19 @@ pub fn touch_pick_events(
             },
             position: touch.position,
         };
+        let retap_enabled = touch_settings.retap_window > Duration::ZERO;
         match touch.phase {
+            TouchPhase::Started if retap_enabled && recent_lifts.lifts.contains_key(&touch.id) => {
+                // A retap: the pointer still exists and is pressed, so we only need to move it.
+                let (last_position, _) = recent_lifts.lifts.remove(&touch.id).unwrap_or_default();
+                debug!("Continuing pointer {:?}", pointer);
+                input_moves.send(InputMove::new(
+                    pointer,
+                    location,
+                    touch.position - last_position,
+                ));
+                location_cache.insert(touch.id, *touch);
+            }
             TouchPhase::Started => {
                 debug!("Spawning pointer {:?}", pointer);
                 commands.spawn((
@@ -51,7 +122,11 @@ pub fn touch_pick_events(
                 ));
 
                 input_moves.send(InputMove::new(pointer, location, Vec2::ZERO));
-                input_presses.send(InputPress::new_down(pointer, PointerButton::Primary));
+                if touch_settings.emit_hover_on_press {
+                    pending_presses.insert(touch.id);
+                } else {
+                    input_presses.send(InputPress::new_down(pointer, PointerButton::Primary));
+                }
                 location_cache.insert(touch.id, *touch);
             }
             TouchPhase::Moved => {
@@ -68,6 +143,30 @@ pub fn touch_pick_events(
                 }
                 location_cache.insert(touch.id, *touch);
             }
+            TouchPhase::Ended if pending_presses.remove(&touch.id) => {
+                // The touch ended before its delayed press was sent, so press it now, to make sure
+                // the tap is still registered.
+                input_presses.send(InputPress::new_down(pointer, PointerButton::Primary));
+                input_presses.send(InputPress::new_up(pointer, PointerButton::Primary));
+                location_cache.remove(&touch.id);
+                cancel_events.send(PointerCancel {
+                    pointer_id: pointer,
+                });
+            }
+            TouchPhase::Canceled if pending_presses.remove(&touch.id) => {
+                // The touch was canceled before it was pressed, so there is nothing to release.
+                location_cache.remove(&touch.id);
+                cancel_events.send(PointerCancel {
+                    pointer_id: pointer,
+                });
+            }
+            TouchPhase::Ended if retap_enabled => {
+                // Delay releasing the pointer, in case the touch is continued by a retap.
+                recent_lifts
+                    .lifts
+                    .insert(touch.id, (touch.position, time.elapsed()));
+                location_cache.remove(&touch.id);
+            }
             TouchPhase::Ended | TouchPhase::Canceled => {
                 input_presses.send(InputPress::new_up(pointer, PointerButton::Primary));
                 location_cache.remove(&touch.id);
@@ -77,6 +176,21 @@ pub fn touch_pick_events(
             }
         }
     }
+
+    // Release the pointers of touches that were not continued within the retap window.
+    let RecentTouchLifts { lifts, expired } = &mut *recent_lifts;
+    lifts.retain(|id, (_, lifted_at)| {
+        if time.elapsed().saturating_sub(*lifted_at) < touch_settings.retap_window {
+            return true;
+        }
+        let pointer = PointerId::Touch(*id);
+        input_presses.send(InputPress::new_up(pointer, PointerButton::Primary));
+        cancel_events.send(PointerCancel {
+            pointer_id: pointer,
+        });
+        expired.push(*id);
+        false
+    });
 }
 
 /// Deactivates unused touch pointers.
@@ -86,21 +200,28 @@ pub fn touch_pick_events(
 pub fn deactivate_touch_pointers(
     mut commands: Commands,
     mut despawn_list: Local<HashSet<(Entity, PointerId)>>,
+    mut recent_lifts: ResMut<RecentTouchLifts>,
     pointers: Query<(Entity, &PointerId)>,
     mut touches: EventReader<TouchInput>,
 ) {
+    let mut despawn = |touch_id: u64| {
+        for (entity, pointer) in &pointers {
+            if pointer.get_touch_id() == Some(touch_id) {
+                despawn_list.insert((entity, *pointer));
+            }
+        }
+    };
     for touch in touches.read() {
         match touch.phase {
-            TouchPhase::Ended | TouchPhase::Canceled => {
-                for (entity, pointer) in &pointers {
-                    if pointer.get_touch_id() == Some(touch.id) {
-                        despawn_list.insert((entity, *pointer));
-                    }
-                }
-            }
+            // Touches waiting for a retap are despawned once their retap window expires.
+            TouchPhase::Ended if recent_lifts.lifts.contains_key(&touch.id) => {}
+            TouchPhase::Ended | TouchPhase::Canceled => despawn(touch.id),
             _ => {}
         }
     }
+    for touch_id in recent_lifts.expired.drain(..) {
+        despawn(touch_id);
+    }
     // A hash set is used to prevent despawning the same entity twice.
     for (entity, pointer) in despawn_list.drain() {
         debug!("Despawning pointer {:?}", pointer);
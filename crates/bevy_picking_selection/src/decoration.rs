@@ -0,0 +1,62 @@
+//! Inserts a component on entities while they are selected, to visualize the selection with
+//! something other than a material swap, like an outline from a third party crate.
+
+use std::marker::PhantomData;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use bevy_picking_core::PickSet;
+
+use crate::{update_state_from_events, PickSelection, SelectionPluginSettings};
+
+/// Adds support for [`SelectionDecoration<C>`], inserting the component `C` on entities while they
+/// are selected.
+pub struct SelectionDecorationPlugin<C: Component + Clone>(PhantomData<C>);
+
+impl<C: Component + Clone> Default for SelectionDecorationPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: Component + Clone> Plugin for SelectionDecorationPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            update_selection_decorations::<C>
+                .after(update_state_from_events)
+                .in_set(PickSet::PostFocus)
+                .run_if(SelectionPluginSettings::should_run),
+        );
+    }
+}
+
+/// Inserts a clone of the contained component on this entity when it is selected, and removes it
+/// when it is deselected. This is useful to visualize the selection with something other than a
+/// material swap, like an outline component from a third party crate.
+///
+/// Requires the [`SelectionDecorationPlugin<C>`] for the component type `C`.
+#[derive(Component, Debug, Clone)]
+pub struct SelectionDecoration<C: Component + Clone>(pub C);
+
+/// Inserts or removes the decoration component `C` when the [`PickSelection`] of an entity with a
+/// [`SelectionDecoration<C>`] changes.
+pub fn update_selection_decorations<C: Component + Clone>(
+    mut commands: Commands,
+    changed: Query<
+        (Entity, &PickSelection, &SelectionDecoration<C>),
+        Or<(Changed<PickSelection>, Added<SelectionDecoration<C>>)>,
+    >,
+) {
+    for (entity, selection, decoration) in &changed {
+        let Some(mut entity) = commands.get_entity(entity) else {
+            continue;
+        };
+        if selection.is_selected {
+            entity.try_insert(decoration.0.clone());
+        } else {
+            entity.remove::<C>();
+        }
+    }
+}
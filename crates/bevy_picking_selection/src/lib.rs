@@ -2,13 +2,16 @@
 //!
 //! This adds the [`Deselect`] and [`Select`] [`Pointer`] events, including support for bubbling
 //! these events.
+//!
+//! To visualize the selection with a component, like an outline from a third party crate, enable
+//! the `decoration` feature and see the `decoration` module.
 
 #![allow(clippy::type_complexity)]
 #![allow(clippy::too_many_arguments)]
 #![deny(missing_docs)]
 
 use bevy_app::prelude::*;
-use bevy_ecs::prelude::*;
+use bevy_ecs::{prelude::*, world::Command};
 use bevy_input::{keyboard::KeyCode, ButtonInput};
 use bevy_reflect::prelude::*;
 use bevy_utils::hashbrown::HashSet;
@@ -16,11 +19,15 @@ use bevy_utils::hashbrown::HashSet;
 use bevy_eventlistener::prelude::*;
 
 use bevy_picking_core::{
+    backend::HitData,
     events::{Click, Down, Pointer},
     pointer::{InputPress, PointerButton, PointerId, PointerLocation},
     PickSet, PickingPluginsSettings,
 };
 
+#[cfg(feature = "decoration")]
+pub mod decoration;
+
 /// Runtime settings for the `bevy_picking_selection` plugin.
 #[derive(Debug, Resource, Reflect)]
 #[reflect(Resource, Default)]
@@ -31,6 +38,10 @@ pub struct SelectionPluginSettings {
     pub click_nothing_deselect_all: bool,
     /// When true, `Ctrl` and `Shift` inputs will trigger multiselect.
     pub use_multiselect_default_inputs: bool,
+    /// When true, clicking an entity toggles its selection without deselecting other entities,
+    /// as if multiselect was always pressed. Clicking nothing still deselects everything if
+    /// [`SelectionPluginSettings::click_nothing_deselect_all`] is enabled.
+    pub toggle_on_click: bool,
 }
 
 impl SelectionPluginSettings {
@@ -54,6 +65,7 @@ impl Default for SelectionPluginSettings {
             is_enabled: true,
             click_nothing_deselect_all: true,
             use_multiselect_default_inputs: true,
+            toggle_on_click: false,
         }
     }
 }
@@ -63,8 +75,10 @@ pub struct SelectionPlugin;
 impl Plugin for SelectionPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SelectionPluginSettings>()
+            .init_resource::<SelectedEntities>()
             .add_event::<Pointer<Select>>()
             .add_event::<Pointer<Deselect>>()
+            .add_event::<SelectionChanged>()
             .add_plugins((
                 EventListenerPlugin::<Pointer<Select>>::default(),
                 EventListenerPlugin::<Pointer<Deselect>>::default(),
@@ -76,7 +90,11 @@ impl Plugin for SelectionPlugin {
                         .chain()
                         .in_set(PickSet::ProcessInput)
                         .run_if(SelectionPluginSettings::multiselect_should_run),
-                    (send_selection_events, update_state_from_events)
+                    (
+                        send_selection_events,
+                        update_state_from_events,
+                        update_selected_entities,
+                    )
                         .chain()
                         .in_set(PickSet::PostFocus)
                         .run_if(SelectionPluginSettings::should_run),
@@ -85,6 +103,7 @@ impl Plugin for SelectionPlugin {
             .register_type::<SelectionPluginSettings>()
             .register_type::<PointerMultiselect>()
             .register_type::<PickSelection>()
+            .register_type::<SelectionChanged>()
             .register_type::<NoDeselect>();
     }
 }
@@ -106,6 +125,44 @@ pub struct PickSelection {
     pub is_selected: bool,
 }
 
+/// A resource that mirrors the set of entities with a [`PickSelection`] that is currently selected.
+///
+/// The [`PickSelection`] component is the source of truth, this resource is kept in sync with it
+/// every frame. This is useful if you want to act on the current selection without iterating over
+/// all selectable entities, and supports change detection, which is only triggered when the set of
+/// selected entities changes.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct SelectedEntities(HashSet<Entity>);
+
+impl SelectedEntities {
+    /// Iterates over all currently selected entities, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Returns `true` if the `entity` is currently selected.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.0.contains(&entity)
+    }
+
+    /// The number of currently selected entities.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns one of the selected entities, if any. Useful when only a single entity can be
+    /// selected at a time. Note that the set is unordered, so with multiple selected entities this
+    /// is not necessarily the first one to have been selected.
+    pub fn first(&self) -> Option<Entity> {
+        self.0.iter().next().copied()
+    }
+}
+
 /// Marker struct used to mark pickable entities for which you don't want to trigger a deselection
 /// event when picked. This is useful for gizmos or other pickable UI entities.
 #[derive(Component, Debug, Default, Copy, Clone, Reflect)]
@@ -113,12 +170,115 @@ pub struct PickSelection {
 pub struct NoDeselect;
 
 /// Fires when an entity has been selected
-#[derive(Copy, Clone, Eq, PartialEq, Debug, Reflect)]
-pub struct Select;
+#[derive(Clone, PartialEq, Debug, Reflect)]
+pub struct Select {
+    /// Pointer button pressed to trigger this event.
+    pub button: PointerButton,
+    /// Information about the picking intersection of the click that selected the entity.
+    pub hit: Option<HitData>,
+}
 
 /// Fires when an entity has been deselected
-#[derive(Copy, Clone, Eq, PartialEq, Debug, Reflect)]
-pub struct Deselect;
+#[derive(Clone, PartialEq, Debug, Reflect)]
+pub struct Deselect {
+    /// Pointer button pressed to trigger this event.
+    pub button: PointerButton,
+    /// Information about the picking intersection of the press that caused the deselection. This
+    /// is the hit on the entity that was pressed, which is not necessarily the deselected entity.
+    /// This is `None` if the pointer pressed on nothing.
+    pub hit: Option<HitData>,
+}
+
+/// Fires once when a [`SetSelection`] command changes the selection of one or more entities,
+/// instead of a [`Select`] or [`Deselect`] event for each entity.
+#[derive(Event, Clone, PartialEq, Debug, Default, Reflect)]
+pub struct SelectionChanged {
+    /// The entities that were selected.
+    pub added: Vec<Entity>,
+    /// The entities that were deselected.
+    pub removed: Vec<Entity>,
+}
+
+/// A [`Command`] that sets the [`PickSelection`] of many entities at once, for example after a box
+/// select.
+///
+/// Instead of a [`Select`] or [`Deselect`] event for each entity, this sends a single
+/// [`SelectionChanged`] event listing the entities whose selection changed, which is much cheaper
+/// for large selections. Because [`PickSelection`] is still updated, the [`SelectedEntities`]
+/// resource, highlighting, and `SelectionDecoration`s are updated as usual.
+///
+/// ```ignore
+/// commands.add(SetSelection::select(box_selected_entities));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SetSelection {
+    /// The entities to update. Entities without a [`PickSelection`] are ignored.
+    pub entities: Vec<Entity>,
+    /// The selection state to set.
+    pub is_selected: bool,
+    /// If `true`, all other selected entities are deselected.
+    pub deselect_others: bool,
+}
+
+impl SetSelection {
+    /// Selects the `entities`, keeping the rest of the selection.
+    pub fn select(entities: impl IntoIterator<Item = Entity>) -> Self {
+        Self {
+            entities: entities.into_iter().collect(),
+            is_selected: true,
+            deselect_others: false,
+        }
+    }
+
+    /// Deselects the `entities`, keeping the rest of the selection.
+    pub fn deselect(entities: impl IntoIterator<Item = Entity>) -> Self {
+        Self {
+            entities: entities.into_iter().collect(),
+            is_selected: false,
+            deselect_others: false,
+        }
+    }
+
+    /// Selects the `entities`, and deselects everything else.
+    pub fn replace(entities: impl IntoIterator<Item = Entity>) -> Self {
+        Self {
+            deselect_others: true,
+            ..Self::select(entities)
+        }
+    }
+}
+
+impl Command for SetSelection {
+    fn apply(self, world: &mut World) {
+        let mut changed = SelectionChanged::default();
+        if self.deselect_others {
+            let targets: HashSet<Entity> = self.entities.iter().copied().collect();
+            let mut selections = world.query::<(Entity, &mut PickSelection)>();
+            for (entity, mut selection) in selections.iter_mut(world) {
+                if selection.is_selected && !targets.contains(&entity) {
+                    selection.is_selected = false;
+                    changed.removed.push(entity);
+                }
+            }
+        }
+        for entity in self.entities {
+            let Some(mut selection) = world.get_mut::<PickSelection>(entity) else {
+                continue;
+            };
+            if selection.is_selected == self.is_selected {
+                continue;
+            }
+            selection.is_selected = self.is_selected;
+            match self.is_selected {
+                true => changed.added.push(entity),
+                false => changed.removed.push(entity),
+            }
+        }
+        if !changed.added.is_empty() || !changed.removed.is_empty() {
+            world.send_event(changed);
+        }
+    }
+}
 
 /// Unsurprising default multiselect inputs: both control and shift keys.
 pub fn multiselect_events(
@@ -158,16 +318,17 @@ pub fn send_selection_events(
         pointer_id,
         pointer_location,
         target,
-        event: _,
+        event: Down { button, hit, .. },
     } in pointer_down
         .read()
         .filter(|pointer| pointer.event.button == PointerButton::Primary)
     {
         pointer_down_list.insert(pointer_id);
-        let multiselect = pointers
-            .iter()
-            .find_map(|(id, multi, _)| (id == pointer_id).then_some(multi.is_pressed))
-            .unwrap_or(false);
+        let multiselect = settings.toggle_on_click
+            || pointers
+                .iter()
+                .find_map(|(id, multi, _)| (id == pointer_id).then_some(multi.is_pressed))
+                .unwrap_or(false);
         let target_can_deselect = no_deselect.get(*target).is_err();
         // Deselect everything
         if !multiselect && target_can_deselect {
@@ -178,7 +339,10 @@ pub fn send_selection_events(
                         *pointer_id,
                         pointer_location.to_owned(),
                         entity,
-                        Deselect,
+                        Deselect {
+                            button: *button,
+                            hit: Some(hit.clone()),
+                        },
                     ));
                 }
             }
@@ -206,7 +370,15 @@ pub fn send_selection_events(
             if !pointer_down_list.contains(&id) && !multiselect {
                 for (entity, selection) in selectables.iter() {
                     if selection.is_selected {
-                        deselections.send(Pointer::new(id, location.clone(), entity, Deselect));
+                        deselections.send(Pointer::new(
+                            id,
+                            location.clone(),
+                            entity,
+                            Deselect {
+                                button: press.button,
+                                hit: None,
+                            },
+                        ));
                     }
                 }
             }
@@ -217,15 +389,16 @@ pub fn send_selection_events(
         pointer_id,
         pointer_location,
         target,
-        event: _,
+        event: Click { button, hit },
     } in pointer_click
         .read()
         .filter(|pointer| pointer.event.button == PointerButton::Primary)
     {
-        let multiselect = pointers
-            .iter()
-            .find_map(|(id, multi, _)| id.eq(pointer_id).then_some(multi.is_pressed))
-            .unwrap_or(false);
+        let multiselect = settings.toggle_on_click
+            || pointers
+                .iter()
+                .find_map(|(id, multi, _)| id.eq(pointer_id).then_some(multi.is_pressed))
+                .unwrap_or(false);
         if let Ok((entity, selection)) = selectables.get(*target) {
             if multiselect {
                 match selection.is_selected {
@@ -234,7 +407,10 @@ pub fn send_selection_events(
                             *pointer_id,
                             pointer_location.to_owned(),
                             entity,
-                            Deselect,
+                            Deselect {
+                                button: *button,
+                                hit: Some(hit.clone()),
+                            },
                         ));
                     }
                     false => {
@@ -242,7 +418,10 @@ pub fn send_selection_events(
                             *pointer_id,
                             pointer_location.to_owned(),
                             entity,
-                            Select,
+                            Select {
+                                button: *button,
+                                hit: Some(hit.clone()),
+                            },
                         ));
                     }
                 };
@@ -251,7 +430,10 @@ pub fn send_selection_events(
                     *pointer_id,
                     pointer_location.to_owned(),
                     entity,
-                    Select,
+                    Select {
+                        button: *button,
+                        hit: Some(hit.clone()),
+                    },
                 ));
             }
         }
@@ -275,3 +457,24 @@ pub fn update_state_from_events(
         }
     }
 }
+
+/// Mirrors changes to [`PickSelection`] components into the [`SelectedEntities`] resource.
+pub fn update_selected_entities(
+    mut selected: ResMut<SelectedEntities>,
+    changed: Query<(Entity, &PickSelection), Changed<PickSelection>>,
+    mut removed: RemovedComponents<PickSelection>,
+) {
+    for entity in removed.read() {
+        if selected.contains(entity) {
+            selected.0.remove(&entity);
+        }
+    }
+    for (entity, selection) in &changed {
+        if selection.is_selected != selected.contains(entity) {
+            match selection.is_selected {
+                true => selected.0.insert(entity),
+                false => selected.0.remove(&entity),
+            };
+        }
+    }
+}
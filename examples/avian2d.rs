@@ -0,0 +1,52 @@
+//! Demonstrates how to use the Avian 2d picking backend.
+
+use avian2d::prelude::*;
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use bevy_mod_picking::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins.set(low_latency_window_plugin()),
+            // We want to disable the sprite backend because it is enabled by default. All supplied
+            // backends are optional. In your app, you can disable the default features of the
+            // plugin and only enable the backends you want to use.
+            DefaultPickingPlugins.build().disable::<SpriteBackend>(),
+            PhysicsPlugins::default(),
+            PhysicsDebugPlugin::default(),
+        ))
+        .insert_resource(DebugPickingMode::Normal)
+        .insert_resource(Gravity::ZERO)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(Circle::new(50.0)).into(),
+            material: materials.add(Color::WHITE),
+            transform: Transform::from_xyz(-100.0, 0.0, 0.0),
+            ..default()
+        },
+        Collider::circle(50.0),
+        PickableBundle::default(), // Optional: adds selection, highlighting, and helper components.
+    ));
+    // This square overlaps the circle, and is in front of it, because it has a higher Z.
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(Rectangle::new(100.0, 100.0)).into(),
+            material: materials.add(Color::WHITE),
+            transform: Transform::from_xyz(-50.0, 0.0, 1.0),
+            ..default()
+        },
+        Collider::rectangle(100.0, 100.0),
+        PickableBundle::default(), // Optional: adds selection, highlighting, and helper components.
+    ));
+}
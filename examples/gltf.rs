@@ -71,4 +71,5 @@ const HIGHLIGHT_TINT: Highlight<StandardMaterial> = Highlight {
             .mix(&Color::srgba(-0.4, 0.8, -0.4, 0.0), 0.5), // selected is green
         ..matl.to_owned()
     })),
+    dragging: None,
 };
@@ -0,0 +1,69 @@
+//! Demonstrates how to highlight an entity with more than one asset type. Here, hovering a cube
+//! changes both its material and its mesh, using a highlight plugin for each asset type.
+
+use bevy::prelude::*;
+use bevy_mod_picking::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins.set(low_latency_window_plugin()),
+            DefaultPickingPlugins,
+            // The default plugins highlight `StandardMaterial`s. Add a highlight plugin for every
+            // other asset type that should be highlighted, in this case the mesh.
+            HighlightPlugin::<Mesh> {
+                highlighting_default: |mut meshes| GlobalHighlight {
+                    hovered: meshes.add(Cuboid::from_length(1.1)),
+                    pressed: meshes.add(Cuboid::from_length(1.05)),
+                    selected: meshes.add(Cuboid::from_length(1.1)),
+                    dragging: None,
+                },
+            },
+        ))
+        .insert_resource(DebugPickingMode::Normal)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// set up a simple 3D scene
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // cubes, highlighted with both the `StandardMaterial` and the `Mesh` highlight plugins
+    for x in [-1.0, 1.0] {
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Cuboid::default()),
+                material: materials.add(Color::srgb(0.8, 0.7, 0.6)),
+                transform: Transform::from_xyz(x, 0.5, 0.0),
+                ..default()
+            },
+            PickableBundle::default(), // <- Makes the mesh pickable and highlightable.
+        ));
+    }
+
+    // plane
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(bevy_render::mesh::PlaneMeshBuilder::from_length(5.0)),
+        material: materials.add(Color::srgb(0.3, 0.5, 0.3)),
+        ..default()
+    });
+
+    // light
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, -4.0),
+        ..default()
+    });
+
+    // camera
+    commands.spawn((Camera3dBundle {
+        transform: Transform::from_xyz(3.0, 3.0, 3.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    },));
+}
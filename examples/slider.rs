@@ -0,0 +1,71 @@
+//! Demonstrates how to use [`DragConstraint`] to build a horizontal slider, and a knob that snaps
+//! to a grid, without any snapping math in the drag listeners.
+
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use bevy_color::palettes;
+use bevy_mod_picking::prelude::*;
+
+const TRACK_WIDTH: f32 = 400.0;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(low_latency_window_plugin()))
+        .add_plugins(DefaultPickingPlugins)
+        .insert_resource(DebugPickingMode::Normal)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+
+    // The slider track, which is not pickable, so it doesn't block the handle.
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(Rectangle::new(TRACK_WIDTH, 8.0)).into(),
+            transform: Transform::from_xyz(0.0, 100.0, 0.0),
+            material: materials.add(ColorMaterial::from(Color::from(palettes::basic::GRAY))),
+            ..default()
+        },
+        Pickable::IGNORE,
+    ));
+
+    // The slider handle can only be dragged horizontally.
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(Rectangle::new(24.0, 48.0)).into(),
+            transform: Transform::from_xyz(-TRACK_WIDTH / 2.0, 100.0, 1.0),
+            material: materials.add(ColorMaterial::from(Color::from(palettes::basic::PURPLE))),
+            ..default()
+        },
+        PickableBundle::default(),
+        DragConstraint::HORIZONTAL,
+        On::<Pointer<Drag>>::target_component_mut::<Transform>(|drag, transform| {
+            // `drag.delta.y` is always zero, thanks to the constraint.
+            let x = (transform.translation.x + drag.delta.x)
+                .clamp(-TRACK_WIDTH / 2.0, TRACK_WIDTH / 2.0);
+            transform.translation.x = x;
+            info!("Slider value: {:.2}", x / TRACK_WIDTH + 0.5);
+        }),
+    ));
+
+    // This square moves in increments of 50 pixels.
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(Rectangle::new(50.0, 50.0)).into(),
+            transform: Transform::from_xyz(0.0, -100.0, 1.0),
+            material: materials.add(ColorMaterial::from(Color::from(palettes::basic::TEAL))),
+            ..default()
+        },
+        PickableBundle::default(),
+        DragConstraint::grid(50.0),
+        On::<Pointer<Drag>>::target_component_mut::<Transform>(|drag, transform| {
+            transform.translation.x += drag.delta.x;
+            transform.translation.y -= drag.delta.y;
+        }),
+    ));
+}
@@ -41,6 +41,7 @@ const HIGHLIGHT_TINT: Highlight<StandardMaterial> = Highlight {
             .mix(&Color::srgba(-0.4, 0.8, -0.4, 0.0), 0.5), // selected is green
         ..matl.to_owned()
     })),
+    dragging: None,
 };
 
 /// set up a simple 3D scene
@@ -9,10 +9,14 @@ use picking_core::{backend::HitData, events::DragMap, pointer::Location};
 use crate::*;
 
 use bevy_app::prelude::*;
+use bevy_color::{Color, Hsla};
 use bevy_math::prelude::*;
 use bevy_reflect::prelude::*;
 use bevy_render::prelude::*;
-use bevy_utils::tracing::{debug, trace};
+use bevy_utils::{
+    tracing::{debug, trace},
+    HashMap,
+};
 
 /// This resource determines the runtime behavior of the debug plugin.
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Resource)]
@@ -41,6 +45,49 @@ impl DebugPickingMode {
     }
 }
 
+/// Configures the appearance of the debug overlay.
+#[derive(Debug, Clone, Resource)]
+pub struct DebugPickingConfig {
+    /// Colors used for the debug overlay of specific pointers, instead of the color derived from
+    /// the [`PointerId`] by [`DebugPickingConfig::pointer_color`].
+    pub pointer_colors: HashMap<PointerId, Color>,
+    /// The size, in world units, of the gizmos drawn at hit positions in
+    /// [`DebugPickingMode::Noisy`]. The normal arrow is five times this length. Defaults to `0.05`.
+    pub hit_gizmo_size: f32,
+}
+
+impl Default for DebugPickingConfig {
+    fn default() -> Self {
+        Self {
+            pointer_colors: HashMap::default(),
+            hit_gizmo_size: 0.05,
+        }
+    }
+}
+
+impl DebugPickingConfig {
+    /// The color used to draw the debug overlay of this pointer.
+    ///
+    /// Unless overridden in [`DebugPickingConfig::pointer_colors`], the mouse is always white, and
+    /// other pointers get a stable hue derived from their id, so they can be told apart.
+    pub fn pointer_color(&self, id: PointerId) -> Color {
+        if let Some(color) = self.pointer_colors.get(&id) {
+            return *color;
+        }
+        let seed = match id {
+            PointerId::Mouse => return Color::WHITE,
+            PointerId::Touch(index) => index,
+            PointerId::Custom(uuid) => {
+                let (high, low) = uuid.as_u64_pair();
+                high ^ low
+            }
+        };
+        // Stepping by the golden angle keeps consecutive touch ids far apart on the color wheel.
+        let hue = (seed % 360) as f32 * 137.508 % 360.0;
+        Hsla::hsl(hue, 0.8, 0.7).into()
+    }
+}
+
 /// Logs events for debugging
 ///
 /// "Normal" events are logged at the `debug` level. "Noisy" events are logged at the `trace` level.
@@ -58,6 +105,9 @@ impl DebugPickingMode {
 /// You can also change the log filter at runtime in your code. The [LogPlugin
 /// docs](https://docs.rs/bevy/latest/bevy/log/struct.LogPlugin.html) give an example.
 ///
+/// The colors used for each pointer in the debug overlay can be changed with the
+/// [`DebugPickingConfig`] resource.
+///
 /// Use the [`DebugPickingMode`] state resource to control this plugin. Example:
 ///
 /// ```ignore
@@ -85,6 +135,7 @@ pub struct DebugPickingPlugin;
 impl Plugin for DebugPickingPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DebugPickingMode>()
+            .init_resource::<DebugPickingConfig>()
             .add_systems(
                 PreUpdate,
                 pointer_debug_visibility.in_set(picking_core::PickSet::PostFocus),
@@ -109,6 +160,8 @@ impl Plugin for DebugPickingPlugin {
                     log_pointer_event_trace::<events::DragOver>.run_if(DebugPickingMode::is_noisy),
                     log_pointer_event_debug::<events::DragLeave>,
                     log_pointer_event_debug::<events::Drop>,
+                    log_pointer_event_debug::<events::HoldRepeat>,
+                    log_pointer_event_debug::<events::ContextMenu>,
                 )
                     .distributive_run_if(DebugPickingMode::is_enabled)
                     .in_set(picking_core::PickSet::Last),
@@ -134,6 +187,13 @@ impl Plugin for DebugPickingPlugin {
                 .in_set(picking_core::PickSet::Last),
         );
 
+        app.add_systems(
+            Update,
+            debug_draw_hits
+                .run_if(DebugPickingMode::is_noisy)
+                .run_if(resource_exists::<bevy_gizmos::config::GizmoConfigStore>),
+        );
+
         #[cfg(feature = "selection")]
         app.add_systems(
             Update,
@@ -269,16 +329,7 @@ pub fn update_debug_data(
                 .get(id)
                 .iter()
                 .flat_map(|h| h.iter())
-                .map(|(e, h)| {
-                    (
-                        if let Ok(name) = names.get(*e) {
-                            DebugName::Name(name.clone(), *e)
-                        } else {
-                            DebugName::Entity(*e)
-                        },
-                        h.to_owned(),
-                    )
-                })
+                .map(|(e, h)| (DebugName::new(*e, &names), h.to_owned()))
                 .collect(),
             drag_start: drag_start(*id),
             #[cfg(feature = "selection")]
@@ -287,22 +338,49 @@ pub fn update_debug_data(
     }
 }
 
+/// Draws a sphere at the position of each hovered hit, and an arrow along its normal, using
+/// [`Gizmos`](bevy_gizmos::gizmos::Gizmos). Hits without a position are skipped. Only runs in
+/// [`DebugPickingMode::Noisy`], and if gizmos are enabled in the app.
+///
+/// This shows where ray based backends hit entities in the world, which the text overlay does not.
+pub fn debug_draw_hits(
+    mut gizmos: bevy_gizmos::gizmos::Gizmos,
+    config: Res<DebugPickingConfig>,
+    pointers: Query<(&pointer::PointerId, &PointerDebug)>,
+) {
+    for (id, debug) in &pointers {
+        let color = config.pointer_color(*id);
+        for (_, hit) in &debug.hits {
+            let Some(position) = hit.position else {
+                continue;
+            };
+            gizmos.sphere(position, Quat::IDENTITY, config.hit_gizmo_size, color);
+            if let Some(normal) = hit.normal {
+                let end = position + normal * config.hit_gizmo_size * 5.0;
+                gizmos.arrow(position, end, color);
+            }
+        }
+    }
+}
+
 /// Draw an egui window on each cursor with debug info
 #[cfg(feature = "backend_egui")]
 pub fn debug_draw_egui(
     mut egui: bevy_egui::EguiContexts,
+    config: Res<DebugPickingConfig>,
     pointers: Query<(&pointer::PointerId, &PointerDebug)>,
 ) {
+    use bevy_color::ColorToPacked;
     use bevy_egui::egui::{self, Color32};
     use bevy_render::camera::NormalizedRenderTarget;
 
-    let transparent_white = Color32::from_rgba_unmultiplied(255, 255, 255, 64);
-    let stroke = egui::Stroke::new(3.0, transparent_white);
-
     for (id, debug) in pointers.iter() {
         let Some(location) = &debug.location else {
             continue;
         };
+        let [r, g, b, _] = config.pointer_color(*id).to_srgba().to_u8_array();
+        let color = Color32::from_rgb(r, g, b);
+        let stroke = egui::Stroke::new(3.0, Color32::from_rgba_unmultiplied(r, g, b, 64));
         let NormalizedRenderTarget::Window(window_ref) = location.target else {
             continue;
         };
@@ -313,7 +391,7 @@ pub fn debug_draw_egui(
         dbg_painter.circle(
             to_egui_pos(location.position),
             20.0,
-            Color32::from_rgba_unmultiplied(255, 255, 255, 32),
+            Color32::from_rgba_unmultiplied(r, g, b, 32),
             stroke,
         );
 
@@ -325,7 +403,7 @@ pub fn debug_draw_egui(
             dbg_painter.debug_text(
                 ((end.to_vec2() + start.to_vec2()) * 0.5).to_pos2(),
                 egui::Align2::CENTER_CENTER,
-                Color32::WHITE,
+                color,
                 format!("{button:?}: [{:.1}, {:.1}]", drag_dist.x, drag_dist.y),
             );
         });
@@ -337,7 +415,7 @@ pub fn debug_draw_egui(
                 - alignment.to_sign() * egui::vec2(20.0, 20.0))
             .to_pos2(),
             alignment,
-            egui::Color32::WHITE,
+            color,
             text,
         );
     }
@@ -350,6 +428,53 @@ pub enum DebugName {
     Entity(Entity),
 }
 
+impl DebugName {
+    /// Names `entity` with its [`Name`], if it has one.
+    pub fn new(entity: Entity, names: &Query<&Name>) -> Self {
+        match names.get(entity) {
+            Ok(name) => Self::Name(name.clone(), entity),
+            Err(_) => Self::Entity(entity),
+        }
+    }
+
+    /// The entity this names.
+    pub fn entity(&self) -> Entity {
+        match self {
+            Self::Name(_, entity) | Self::Entity(entity) => *entity,
+        }
+    }
+
+    /// The [`Name`] of the entity, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Name(name, _) => Some(name.as_str()),
+            Self::Entity(_) => None,
+        }
+    }
+}
+
+/// Returns the entities hovered by each pointer in the [`HoverMap`], sorted from nearest to
+/// farthest, and named with their [`Name`] if they have one.
+///
+/// This is intended for text-based tools, like an in-game console command that checks if a named
+/// entity is hovered, and does not need the [`DebugPickingPlugin`] to be enabled.
+pub fn named_hovers(
+    hover_map: &HoverMap,
+    names: &Query<&Name>,
+) -> HashMap<PointerId, Vec<(DebugName, HitData)>> {
+    hover_map
+        .iter()
+        .map(|(pointer_id, hovered)| {
+            let mut hits: Vec<_> = hovered
+                .iter()
+                .map(|(entity, hit)| (DebugName::new(*entity, names), hit.to_owned()))
+                .collect();
+            hits.sort_by(|a, b| a.1.depth.total_cmp(&b.1.depth));
+            (*pointer_id, hits)
+        })
+        .collect()
+}
+
 impl Debug for DebugName {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -367,6 +492,7 @@ pub fn debug_draw(
     primary_window: Query<Entity, With<bevy_window::PrimaryWindow>>,
     pointers: Query<(Entity, &pointer::PointerId, &PointerDebug)>,
     scale: Res<bevy_ui::UiScale>,
+    config: Res<DebugPickingConfig>,
 ) {
     use bevy_text::prelude::*;
     use bevy_ui::prelude::*;
@@ -404,7 +530,7 @@ pub fn debug_draw(
                         text.clone(),
                         TextStyle {
                             font_size: 12.0,
-                            color: bevy_color::Color::WHITE,
+                            color: config.pointer_color(*id),
                             ..Default::default()
                         },
                     ),
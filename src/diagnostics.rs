@@ -0,0 +1,151 @@
+//! Reports picking statistics to bevy's diagnostics, to help attribute frame time spikes to the
+//! volume of picking hits and events.
+//!
+//! Add the [`PickingDiagnosticsPlugin`] to record per-frame counts of backend hits, hovered
+//! entities, and pointer events of each type. Backends are not identified in [`PointerHits`], so
+//! hits are also counted for each [`PointerHits::order`], which tells the built-in backends apart:
+//! bevy ui hits are reported above the order of their camera, while the sprite and raycasting
+//! backends use the camera order. These can be read from the
+//! [`DiagnosticsStore`](bevy_diagnostic::DiagnosticsStore), or printed with bevy's
+//! `LogDiagnosticsPlugin`. Nothing is measured unless this plugin is added.
+
+use bevy_app::prelude::*;
+use bevy_diagnostic::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, Diagnostics, DiagnosticsStore,
+    RegisterDiagnostic,
+};
+use bevy_ecs::prelude::*;
+use bevy_math::FloatOrd;
+use bevy_reflect::prelude::*;
+use bevy_utils::{HashMap, Instant};
+
+use crate::{
+    backend::PointerHits,
+    events::{
+        Click, ContextMenu, Down, Drag, DragEnd, DragEnter, DragLeave, DragOver, DragStart, Drop,
+        HoldRepeat, Move, Out, Over, Pointer, Up,
+    },
+    focus::HoverMap,
+    picking_core::PickSet,
+};
+
+/// Records picking statistics as bevy [`Diagnostic`]s. See the [module docs](self).
+#[derive(Debug, Default, Clone)]
+pub struct PickingDiagnosticsPlugin;
+
+impl PickingDiagnosticsPlugin {
+    /// The number of [`PointerHits`] events sent by all backends.
+    pub const HIT_EVENTS: DiagnosticPath = DiagnosticPath::const_new("picking/hit_events");
+    /// The number of hits reported by all backends, across all [`PointerHits`] events.
+    pub const HITS: DiagnosticPath = DiagnosticPath::const_new("picking/hits");
+    /// The number of (pointer, entity) pairs in the [`HoverMap`].
+    pub const HOVERED: DiagnosticPath = DiagnosticPath::const_new("picking/hovered");
+
+    /// The diagnostic path used to count the hits of [`PointerHits`] events with this `order`.
+    /// These diagnostics are registered the first time hits are reported with the order.
+    pub fn order_hits_path(order: f32) -> DiagnosticPath {
+        DiagnosticPath::new(format!("picking/hits/order_{order}"))
+    }
+
+    /// The diagnostic path used to count [`Pointer<E>`] events of this type.
+    pub fn event_path<E: Reflect + TypePath>() -> DiagnosticPath {
+        DiagnosticPath::new(format!("picking/events/{}", E::short_type_path()))
+    }
+}
+
+impl Plugin for PickingDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::HIT_EVENTS))
+            .register_diagnostic(Diagnostic::new(Self::HITS))
+            .register_diagnostic(Diagnostic::new(Self::HOVERED))
+            .add_systems(
+                PreUpdate,
+                (measure_hits, measure_hovered).in_set(PickSet::Last),
+            );
+
+        add_event_counter::<Over>(app);
+        add_event_counter::<Out>(app);
+        add_event_counter::<Down>(app);
+        add_event_counter::<Up>(app);
+        add_event_counter::<Click>(app);
+        add_event_counter::<Move>(app);
+        add_event_counter::<DragStart>(app);
+        add_event_counter::<Drag>(app);
+        add_event_counter::<DragEnd>(app);
+        add_event_counter::<DragEnter>(app);
+        add_event_counter::<DragOver>(app);
+        add_event_counter::<DragLeave>(app);
+        add_event_counter::<Drop>(app);
+        add_event_counter::<HoldRepeat>(app);
+        add_event_counter::<ContextMenu>(app);
+    }
+}
+
+/// Registers the diagnostic for [`Pointer<E>`] events, and the system that measures it.
+fn add_event_counter<E: std::fmt::Debug + Clone + Reflect + TypePath>(app: &mut App) {
+    app.register_diagnostic(Diagnostic::new(PickingDiagnosticsPlugin::event_path::<E>()))
+        .add_systems(PreUpdate, measure_events::<E>.in_set(PickSet::Last));
+}
+
+/// Measures the number of hits reported by backends this frame, in total and for each
+/// [`PointerHits::order`].
+pub fn measure_hits(
+    mut diagnostics: Diagnostics,
+    mut commands: Commands,
+    mut hits: EventReader<PointerHits>,
+    mut order_paths: Local<HashMap<FloatOrd, DiagnosticPath>>,
+) {
+    let mut events = 0;
+    let mut hits_per_order = HashMap::<FloatOrd, usize>::new();
+    for event in hits.read() {
+        events += 1;
+        *hits_per_order.entry(FloatOrd(event.order)).or_default() += event.picks.len();
+    }
+    let hits: usize = hits_per_order.values().sum();
+    diagnostics.add_measurement(&PickingDiagnosticsPlugin::HIT_EVENTS, || events as f64);
+    diagnostics.add_measurement(&PickingDiagnosticsPlugin::HITS, || hits as f64);
+
+    // Orders without hits this frame are measured as zero, so their history stays continuous.
+    for (order, path) in order_paths.iter() {
+        let count = hits_per_order.get(order).copied().unwrap_or_default();
+        diagnostics.add_measurement(path, || count as f64);
+    }
+    // Measurements of unregistered diagnostics are dropped, so a new order is registered along
+    // with its first measurement.
+    for (order, count) in hits_per_order.iter() {
+        if order_paths.contains_key(order) {
+            continue;
+        }
+        let path = PickingDiagnosticsPlugin::order_hits_path(order.0);
+        let mut diagnostic = Diagnostic::new(path.clone());
+        diagnostic.add_measurement(DiagnosticMeasurement {
+            time: Instant::now(),
+            value: *count as f64,
+        });
+        commands.add(|world: &mut World| {
+            world.resource_mut::<DiagnosticsStore>().add(diagnostic);
+        });
+        order_paths.insert(*order, path);
+    }
+}
+
+/// Measures the number of hovered entities this frame.
+pub fn measure_hovered(mut diagnostics: Diagnostics, hover_map: Res<HoverMap>) {
+    diagnostics.add_measurement(&PickingDiagnosticsPlugin::HOVERED, || {
+        hover_map
+            .values()
+            .map(|hovered| hovered.len())
+            .sum::<usize>() as f64
+    });
+}
+
+/// Measures the number of [`Pointer<E>`] events sent this frame.
+pub fn measure_events<E: std::fmt::Debug + Clone + Reflect + TypePath>(
+    mut diagnostics: Diagnostics,
+    mut events: EventReader<Pointer<E>>,
+    mut path: Local<Option<DiagnosticPath>>,
+) {
+    let count = events.read().count();
+    let path = path.get_or_insert_with(PickingDiagnosticsPlugin::event_path::<E>);
+    diagnostics.add_measurement(path, || count as f64);
+}
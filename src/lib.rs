@@ -185,7 +185,7 @@
 #![allow(clippy::too_many_arguments)]
 #![deny(missing_docs)]
 
-use bevy_ecs::prelude::*;
+use bevy_ecs::{prelude::*, system::EntityCommands};
 use bevy_picking_core::PointerCoreBundle;
 use prelude::*;
 
@@ -198,11 +198,19 @@ pub use bevy_picking_highlight as highlight;
 pub use bevy_picking_selection as selection;
 #[cfg(feature = "debug")]
 pub mod debug;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
 
 /// Picking backend exports, feature-gated.
 pub mod backends {
+    #[cfg(feature = "backend_aabb")]
+    pub use bevy_picking_aabb as aabb;
     #[cfg(feature = "backend_avian")]
     pub use bevy_picking_avian as avian;
+    #[cfg(feature = "backend_avian2d")]
+    pub use bevy_picking_avian2d as avian2d;
     #[cfg(feature = "backend_egui")]
     pub use bevy_picking_egui as egui;
     #[cfg(feature = "backend_rapier")]
@@ -220,18 +228,29 @@ pub mod backends {
 /// Common imports
 pub mod prelude {
     #[cfg(feature = "debug")]
-    pub use crate::debug::{DebugPickingMode, DebugPickingPlugin};
+    pub use crate::debug::{DebugPickingConfig, DebugPickingMode, DebugPickingPlugin};
+    #[cfg(feature = "diagnostics")]
+    pub use crate::diagnostics::PickingDiagnosticsPlugin;
     pub use crate::{
         backends,
         events::{
-            Click, Down, Drag, DragEnd, DragEnter, DragLeave, DragOver, DragStart, Drop, Move, Out,
-            Over, Pointer, Up,
+            ClampDragToEntity, Click, ClickSettings, ContextMenu, ContextMenuSettings, Down, Drag,
+            DragConstraint, DragEnd, DragEnter, DragLeave, DragOver, DragPayload, DragStart, Drop,
+            Edge, GestureMap, GestureState, GestureTransition, HoldRepeat, HoldRepeatSettings,
+            Move, Out, Over, Pointer, RecordTo, SendToEntity, Up,
+        },
+        focus::{
+            EntityPointers, GlobalPickState, HoverDelay, InstancePickable, InteractionChanged,
+            PickingInteraction, PickingModal, PropagateInteraction, WindowInteractions,
         },
-        focus::PickingInteraction,
         input::prelude::*,
-        picking_core::Pickable,
+        picking_core::{
+            IgnoreSubtree, PickPriority, PickTolerance, Pickable, PointerFilter, PointerKindMask,
+            TouchPickSettings,
+        },
         pointer::{
-            PointerButton, PointerId, PointerInteraction, PointerLocation, PointerMap, PointerPress,
+            PointerButton, PointerConfine, PointerId, PointerInputBuffer, PointerInteraction,
+            PointerLocation, PointerMap, PointerMotion, PointerPress,
         },
         *,
     };
@@ -241,13 +260,23 @@ pub mod prelude {
     #[cfg(feature = "highlight")]
     pub use crate::highlight::prelude::*;
 
+    #[cfg(feature = "tooltip")]
+    pub use crate::tooltip::{Tooltip, TooltipPlugin};
+
+    #[cfg(feature = "selection_decoration")]
+    pub use crate::selection::decoration::{SelectionDecoration, SelectionDecorationPlugin};
     #[cfg(feature = "selection")]
     pub use crate::selection::{
-        Deselect, NoDeselect, PickSelection, PointerMultiselect, Select, SelectionPlugin,
+        Deselect, NoDeselect, PickSelection, PointerMultiselect, Select, SelectedEntities,
+        SelectionChanged, SelectionPlugin, SetSelection,
     };
 
+    #[cfg(feature = "backend_aabb")]
+    pub use backends::aabb::prelude::*;
     #[cfg(feature = "backend_avian")]
     pub use backends::avian::prelude::*;
+    #[cfg(feature = "backend_avian2d")]
+    pub use backends::avian2d::prelude::*;
     #[cfg(feature = "backend_bevy_ui")]
     pub use backends::bevy_ui::prelude::*;
     #[cfg(feature = "backend_egui")]
@@ -256,8 +285,6 @@ pub mod prelude {
     pub use backends::rapier::prelude::*;
     #[cfg(feature = "backend_raycast")]
     pub use backends::raycast::prelude::*;
-    #[cfg(feature = "backend_shader")]
-    pub use backends::shader::prelude::*;
     #[cfg(feature = "backend_sprite")]
     pub use backends::sprite::prelude::*;
     #[cfg(feature = "backend_xpbd")]
@@ -279,6 +306,65 @@ pub struct PickableBundle {
     pub highlight: highlight::PickHighlight,
 }
 
+impl PickableBundle {
+    /// Makes a UI node pickable, without the selection and highlighting components. UI nodes have
+    /// no material to highlight, and buttons usually style themselves from their
+    /// [`PickingInteraction`] instead.
+    pub fn ui() -> UiPickableBundle {
+        UiPickableBundle::default()
+    }
+}
+
+/// Makes a UI node pickable, without the selection and highlighting components of
+/// [`PickableBundle`]. See [`PickableBundle::ui`].
+#[derive(Bundle, Default, Clone)]
+pub struct UiPickableBundle {
+    /// Provides overrides for picking behavior.
+    pub pickable: Pickable,
+    /// Tracks entity interaction state.
+    pub interaction: focus::PickingInteraction,
+}
+
+/// Adds methods to [`EntityCommands`] to make an entity pickable in one call.
+pub trait PickableCommandsExt {
+    /// Inserts a [`PickableBundle`], and the marker component of every enabled backend with a
+    /// `require_markers` setting, like `RaycastPickable`, so the entity is pickable whether or not
+    /// markers are required.
+    ///
+    /// Note that when markers are required, cameras also need the marker of the backend.
+    fn make_pickable(&mut self) -> &mut Self;
+
+    /// Like [`PickableCommandsExt::make_pickable`], using the supplied [`Pickable`] to customize
+    /// blocking and hovering.
+    fn make_pickable_with(&mut self, pickable: Pickable) -> &mut Self;
+}
+
+impl PickableCommandsExt for EntityCommands<'_> {
+    fn make_pickable(&mut self) -> &mut Self {
+        self.make_pickable_with(Pickable::default())
+    }
+
+    fn make_pickable_with(&mut self, pickable: Pickable) -> &mut Self {
+        self.insert(PickableBundle {
+            pickable,
+            ..Default::default()
+        });
+        #[cfg(feature = "backend_raycast")]
+        self.insert(backends::raycast::RaycastPickable);
+        #[cfg(feature = "backend_rapier")]
+        self.insert(backends::rapier::RapierPickable);
+        #[cfg(feature = "backend_xpbd")]
+        self.insert(backends::xpbd::XpbdPickable);
+        #[cfg(feature = "backend_avian")]
+        self.insert(backends::avian::AvianPickable);
+        #[cfg(feature = "backend_avian2d")]
+        self.insert(backends::avian2d::Avian2dPickable);
+        #[cfg(feature = "backend_aabb")]
+        self.insert(backends::aabb::AabbPickable);
+        self
+    }
+}
+
 /// Bundle of components needed for a fully-featured pointer.
 #[derive(Bundle)]
 pub struct PointerBundle {
@@ -321,6 +407,11 @@ impl bevy_app::PluginGroup for DefaultPickingPlugins {
             builder = builder.add(highlight::DefaultHighlightingPlugin);
         }
 
+        #[cfg(feature = "tooltip")]
+        {
+            builder = builder.add(tooltip::TooltipPlugin);
+        }
+
         #[cfg(feature = "selection")]
         {
             builder = builder.add(selection::SelectionPlugin);
@@ -342,10 +433,6 @@ impl bevy_app::PluginGroup for DefaultPickingPlugins {
         {
             builder = builder.add(bevy_picking_xpbd::XpbdBackend);
         }
-        #[cfg(feature = "backend_shader")]
-        {
-            builder = builder.add(bevy_picking_shader::ShaderBackend);
-        }
         #[cfg(feature = "backend_sprite")]
         {
             builder = builder.add(bevy_picking_sprite::SpriteBackend);
@@ -358,6 +445,14 @@ impl bevy_app::PluginGroup for DefaultPickingPlugins {
         {
             builder = builder.add(bevy_picking_avian::AvianBackend);
         }
+        #[cfg(feature = "backend_avian2d")]
+        {
+            builder = builder.add(bevy_picking_avian2d::Avian2dBackend);
+        }
+        #[cfg(feature = "backend_aabb")]
+        {
+            builder = builder.add(bevy_picking_aabb::AabbBackend);
+        }
 
         builder
     }
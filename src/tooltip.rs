@@ -0,0 +1,185 @@
+//! A drop-in tooltip for entities hovered by the mouse pointer.
+//!
+//! Add a [`Tooltip`] to any pickable entity, and a text node will be shown next to the cursor once
+//! the mouse has hovered the entity for [`Tooltip::delay`]. The tooltip is removed as soon as the
+//! pointer leaves the entity.
+
+use std::time::Duration;
+
+use bevy_app::prelude::*;
+use bevy_math::FloatOrd;
+use bevy_reflect::prelude::*;
+use bevy_render::prelude::*;
+use bevy_time::Time;
+use bevy_utils::tracing::debug;
+
+use crate::*;
+use picking_core::focus::HoverMap;
+
+/// Adds support for the [`Tooltip`] component.
+#[derive(Debug, Default, Clone)]
+pub struct TooltipPlugin;
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            update_tooltips.in_set(picking_core::PickSet::Last),
+        )
+        .register_type::<Tooltip>();
+    }
+}
+
+/// Shows a text tooltip next to the mouse pointer while it hovers this entity.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Tooltip {
+    /// The text to display.
+    pub text: String,
+    /// How long the mouse needs to hover the entity before the tooltip is shown.
+    pub delay: Duration,
+}
+
+impl Tooltip {
+    /// The delay used by [`Tooltip::new`].
+    pub const DEFAULT_DELAY: Duration = Duration::from_millis(500);
+
+    /// Create a tooltip with the [default delay](Self::DEFAULT_DELAY).
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            delay: Self::DEFAULT_DELAY,
+        }
+    }
+
+    /// Sets the hover [`delay`](Self::delay) of the tooltip.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+/// Marks the UI node spawned to display a [`Tooltip`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TooltipNode;
+
+/// The tooltip currently being tracked by [`update_tooltips`].
+#[derive(Debug, Default)]
+pub struct ActiveTooltip {
+    target: Option<Entity>,
+    hovered_for: Duration,
+    node: Option<Entity>,
+}
+
+/// Spawns, moves, and despawns the tooltip node based on what the mouse pointer is hovering.
+pub fn update_tooltips(
+    mut commands: Commands,
+    mut active: Local<ActiveTooltip>,
+    time: Res<Time>,
+    hover_map: Res<HoverMap>,
+    pointer_map: Res<PointerMap>,
+    pointers: Query<&PointerLocation>,
+    tooltips: Query<&Tooltip>,
+    camera_query: Query<(Entity, &Camera)>,
+    primary_window: Query<Entity, With<bevy_window::PrimaryWindow>>,
+    scale: Res<bevy_ui::UiScale>,
+    mut nodes: Query<&mut bevy_ui::Style, With<TooltipNode>>,
+) {
+    use bevy_text::prelude::*;
+    use bevy_ui::prelude::*;
+
+    // The nearest hovered entity with a tooltip
+    let target = hover_map
+        .get(&PointerId::Mouse)
+        .iter()
+        .flat_map(|hovered| hovered.iter())
+        .filter(|(entity, _)| tooltips.contains(**entity))
+        .min_by_key(|(_, hit)| FloatOrd(hit.depth))
+        .map(|(entity, _)| *entity);
+
+    if target != active.target {
+        if let Some(mut node) = active.node.and_then(|node| commands.get_entity(node)) {
+            node.despawn();
+        }
+        *active = ActiveTooltip {
+            target,
+            ..Default::default()
+        };
+    }
+
+    let Some(target) = target else {
+        return;
+    };
+    let Ok(tooltip) = tooltips.get(target) else {
+        return;
+    };
+    active.hovered_for += time.delta();
+    if active.hovered_for < tooltip.delay {
+        return;
+    }
+
+    let Some(location) = pointer_map
+        .get_entity(PointerId::Mouse)
+        .and_then(|entity| pointers.get(entity).ok())
+        .and_then(|pointer| pointer.location())
+    else {
+        return;
+    };
+    let Some((camera, viewport)) = camera_query
+        .iter()
+        .filter(|(_, camera)| camera.is_active)
+        .find(|(_, camera)| {
+            camera
+                .target
+                .normalize(primary_window.get_single().ok())
+                .as_ref()
+                == Some(&location.target)
+        })
+        .map(|(entity, camera)| (entity, camera.logical_viewport_rect()))
+    else {
+        return;
+    };
+
+    let mut pointer_pos = location.position;
+    if let Some(viewport) = viewport {
+        pointer_pos -= viewport.min;
+    }
+    let left = Val::Px(pointer_pos.x + 12.0) / scale.0;
+    let top = Val::Px(pointer_pos.y + 12.0) / scale.0;
+
+    if let Some(mut style) = active.node.and_then(|node| nodes.get_mut(node).ok()) {
+        style.left = left;
+        style.top = top;
+        return;
+    }
+
+    debug!("Showing tooltip for {target:?}");
+    let node = commands
+        .spawn((
+            TextBundle {
+                text: Text::from_section(
+                    tooltip.text.clone(),
+                    TextStyle {
+                        font_size: 14.0,
+                        color: bevy_color::Color::WHITE,
+                        ..Default::default()
+                    },
+                ),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left,
+                    top,
+                    padding: UiRect::all(Val::Px(4.0)),
+                    ..Default::default()
+                },
+                background_color: bevy_color::Color::srgba(0.1, 0.1, 0.1, 0.9).into(),
+                z_index: ZIndex::Global(i32::MAX),
+                ..Default::default()
+            },
+            TargetCamera(camera),
+            // The tooltip should never steal hover from the entity it describes.
+            Pickable::IGNORE,
+            TooltipNode,
+        ))
+        .id();
+    active.node = Some(node);
+}